@@ -0,0 +1,12 @@
+-- 같은 사건을 다루는 뉴스들을 하나의 스토리로 묶기 위한 클러스터
+CREATE TABLE IF NOT EXISTS news_cluster (
+    news_cluster_id INT AUTO_INCREMENT PRIMARY KEY,
+    representative_news_id INT NOT NULL,
+    news_cluster_size INT NOT NULL DEFAULT 1,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+ALTER TABLE news
+    ADD COLUMN news_cluster_id INT NULL,
+    ADD INDEX idx_news_cluster_id (news_cluster_id);
@@ -0,0 +1,11 @@
+-- 모델별 일일 LLM 사용량 및 예상 비용(원) 기록
+CREATE TABLE IF NOT EXISTS llm_usage (
+    usage_date DATE NOT NULL,
+    provider VARCHAR(50) NOT NULL,
+    model VARCHAR(100) NOT NULL,
+    request_count INT NOT NULL DEFAULT 0,
+    input_tokens BIGINT NOT NULL DEFAULT 0,
+    output_tokens BIGINT NOT NULL DEFAULT 0,
+    estimated_cost DOUBLE NOT NULL DEFAULT 0,
+    PRIMARY KEY (usage_date, provider, model)
+);
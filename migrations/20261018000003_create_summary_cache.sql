@@ -0,0 +1,9 @@
+-- 정규화된 본문 해시 + 프롬프트 버전 + 모델 기준 요약 캐시
+CREATE TABLE IF NOT EXISTS summary_cache (
+    content_hash CHAR(64) NOT NULL,
+    prompt_version INT NOT NULL,
+    model VARCHAR(100) NOT NULL,
+    summary TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (content_hash, prompt_version, model)
+);
@@ -0,0 +1,19 @@
+-- LLM 구조화 분석 결과(요약, 키워드, 카테고리, 개체명, 감성)
+ALTER TABLE summary_cache
+    ADD COLUMN analysis TEXT NULL;
+
+ALTER TABLE news
+    ADD COLUMN news_keywords TEXT NULL,
+    ADD COLUMN news_entities TEXT NULL,
+    ADD COLUMN news_sentiment VARCHAR(10) NULL,
+    ADD COLUMN news_analysis_category VARCHAR(20) NULL,
+    ADD INDEX idx_news_analysis_category (news_analysis_category);
+
+ALTER TABLE rss_item
+    ADD COLUMN rss_summary VARCHAR(200) NULL,
+    ADD COLUMN rss_keywords TEXT NULL,
+    ADD COLUMN rss_entities TEXT NULL,
+    ADD COLUMN rss_sentiment VARCHAR(10) NULL,
+    ADD COLUMN rss_analysis_category VARCHAR(20) NULL,
+    ADD COLUMN rss_analyzed_at DATETIME NULL,
+    ADD INDEX idx_rss_item_analyzed_at (rss_analyzed_at);
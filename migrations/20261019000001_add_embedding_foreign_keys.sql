@@ -0,0 +1,27 @@
+-- 뉴스나 RSS 아이템이 삭제되면 임베딩도 함께 삭제되도록 외래 키 추가
+-- 기존에 남아 있던 연결이 끊긴 임베딩은 먼저 정리함
+DELETE FROM embedding
+WHERE news_id IS NOT NULL
+    AND NOT EXISTS (SELECT 1 FROM news WHERE news.news_id = embedding.news_id);
+
+DELETE FROM embedding
+WHERE rss_id IS NOT NULL
+    AND NOT EXISTS (SELECT 1 FROM rss_item WHERE rss_item.rss_id = embedding.rss_id);
+
+ALTER TABLE embedding
+    ADD CONSTRAINT fk_embedding_news
+        FOREIGN KEY (news_id) REFERENCES news (news_id) ON DELETE CASCADE,
+    ADD CONSTRAINT fk_embedding_rss_item
+        FOREIGN KEY (rss_id) REFERENCES rss_item (rss_id) ON DELETE CASCADE;
+
+-- 클러스터가 삭제되면 소속 뉴스의 클러스터 ID만 비움
+UPDATE news
+SET news_cluster_id = NULL
+WHERE news_cluster_id IS NOT NULL
+    AND NOT EXISTS (
+        SELECT 1 FROM news_cluster WHERE news_cluster.news_cluster_id = news.news_cluster_id
+    );
+
+ALTER TABLE news
+    ADD CONSTRAINT fk_news_cluster
+        FOREIGN KEY (news_cluster_id) REFERENCES news_cluster (news_cluster_id) ON DELETE SET NULL;
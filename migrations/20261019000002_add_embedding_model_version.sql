@@ -0,0 +1,24 @@
+-- 임베딩을 만든 모델을 기록해 모델을 바꿀 때 벡터 공간이 섞이지 않도록 함
+CREATE TABLE IF NOT EXISTS embedding_model (
+    embedding_model_version INT PRIMARY KEY,
+    embedding_model_id VARCHAR(100) NOT NULL,
+    embedding_model_dimension INT NOT NULL,
+    -- migrating: 재임베딩 중, active: 인덱스/검색에 사용 중, retired: 이전 버전
+    embedding_model_status VARCHAR(20) NOT NULL DEFAULT 'migrating',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    activated_at DATETIME NULL
+);
+
+INSERT IGNORE INTO embedding_model
+    (embedding_model_version, embedding_model_id, embedding_model_dimension, embedding_model_status, activated_at)
+VALUES (1, 'distiluse-base-multilingual-cased', 512, 'active', NOW());
+
+ALTER TABLE embedding
+    ADD COLUMN embedding_model_id VARCHAR(100) NULL,
+    ADD COLUMN embedding_model_version INT NULL,
+    ADD INDEX idx_embedding_model_version (embedding_model_version);
+
+-- 기존 임베딩은 모두 첫 번째 모델로 생성됨
+UPDATE embedding
+SET embedding_model_id = 'distiluse-base-multilingual-cased', embedding_model_version = 1
+WHERE embedding_model_version IS NULL;
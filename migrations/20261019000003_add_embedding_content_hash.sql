@@ -0,0 +1,4 @@
+-- 임베딩한 텍스트의 정규화 해시. 같은 모델 버전에서 텍스트가 같으면 모델을 다시 호출하지 않고 재사용함
+ALTER TABLE embedding
+    ADD COLUMN embedding_content_hash CHAR(64) NULL,
+    ADD INDEX idx_embedding_content_hash (embedding_content_hash, embedding_model_version);
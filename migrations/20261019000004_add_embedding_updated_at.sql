@@ -0,0 +1,5 @@
+-- 인덱스를 마지막으로 만든 뒤 바뀐 임베딩을 찾기 위한 워터마크.
+-- 채널 임베딩은 같은 행을 갱신하므로 `embedding_id`만으로는 변경을 알 수 없음
+ALTER TABLE embedding
+    ADD COLUMN embedding_updated_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6),
+    ADD INDEX idx_embedding_model_version_updated_at (embedding_model_version, embedding_updated_at);
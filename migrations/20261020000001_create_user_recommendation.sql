@@ -0,0 +1,29 @@
+-- 사용자가 읽은 RSS 아이템과 뉴스. 앱 서버가 기록하며, 추천에서 관심사를 계산하고 읽은 항목을 제외할 때 사용함
+CREATE TABLE IF NOT EXISTS user_read_history (
+    user_read_history_id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_id INT NOT NULL,
+    rss_id INT NULL,
+    news_id INT NULL,
+    read_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_user_read_history_user_read_at (user_id, read_at),
+    CONSTRAINT fk_user_read_history_rss_item
+        FOREIGN KEY (rss_id) REFERENCES rss_item (rss_id) ON DELETE CASCADE,
+    CONSTRAINT fk_user_read_history_news
+        FOREIGN KEY (news_id) REFERENCES news (news_id) ON DELETE CASCADE
+);
+
+-- 매일 만드는 사용자별 추천 목록. 다시 만들 때 이전 목록을 교체함
+CREATE TABLE IF NOT EXISTS user_recommendation (
+    user_recommendation_id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_id INT NOT NULL,
+    recommendation_rank INT NOT NULL,
+    rss_id INT NULL,
+    news_id INT NULL,
+    recommendation_score DOUBLE NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_user_recommendation_user_rank (user_id, recommendation_rank),
+    CONSTRAINT fk_user_recommendation_rss_item
+        FOREIGN KEY (rss_id) REFERENCES rss_item (rss_id) ON DELETE CASCADE,
+    CONSTRAINT fk_user_recommendation_news
+        FOREIGN KEY (news_id) REFERENCES news (news_id) ON DELETE CASCADE
+);
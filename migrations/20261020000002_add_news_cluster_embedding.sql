@@ -0,0 +1,5 @@
+-- 클러스터 비교용 임베딩(제목 + 설명). 새 뉴스는 요약 전에 비교하므로 저장용 임베딩(제목 + 요약)과
+-- 같은 텍스트로 만든 벡터를 따로 보관함
+ALTER TABLE news
+    ADD COLUMN news_cluster_embedding MEDIUMBLOB NULL,
+    ADD COLUMN news_cluster_embedding_version INT NULL;
@@ -0,0 +1,3 @@
+-- 분석에 실패한 RSS 아이템의 재시도 횟수. `RSS_ANALYSIS_MAX_ATTEMPTS`번 실패하면 더 이상 분석하지 않음
+ALTER TABLE rss_item
+    ADD COLUMN rss_analysis_attempts INT NOT NULL DEFAULT 0;
@@ -1,5 +1,27 @@
+use std::{env, fs, str::FromStr};
+
 use dotenv::dotenv;
+use serde::de::DeserializeOwned;
 
 pub fn load_env() {
     dotenv().ok();
 }
+
+/// 환경 변수를 파싱해서 반환하고, 없거나 파싱에 실패하면 기본값을 사용함.
+pub fn get_env_or<T: FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.trim().parse::<T>().ok())
+        .unwrap_or(default)
+}
+
+/// `key` 환경 변수(없으면 `default_path`)가 가리키는 JSON 설정 파일을 읽음.
+/// 실패하면 경로와 원인을 담은 메시지를 반환하므로, 호출부에서 로그를 남기고 기본값을 사용함.
+pub fn load_json_config<T: DeserializeOwned>(key: &str, default_path: &str) -> Result<T, String> {
+    let path = env::var(key).unwrap_or_else(|_| default_path.to_string());
+
+    fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str::<T>(&text).map_err(|e| e.to_string()))
+        .map_err(|e| format!("{}: {}", path, e))
+}
@@ -17,9 +17,10 @@ use std::env;
 
 // 스케줄러 이름 상수 정의
 const ANNOY_SCHEDULER: &str = "annoy_scheduler";
-const NEWS_SCHEDULER: &str = "news_scheduler";
+pub const NEWS_SCHEDULER: &str = "news_scheduler";
 const RSS_INFO_SCHEDULER: &str = "rss_info_update_scheduler";
-const RSS_FETCH_AND_NOTIFICATION_SCHEDULER: &str = "rss_fetch_and_notification_scheduler";
+pub const RSS_FETCH_AND_NOTIFICATION_SCHEDULER: &str = "rss_fetch_and_notification_scheduler";
+const RECOMMENDATION_SCHEDULER: &str = "recommendation_scheduler";
 
 pub fn load_logger() {
     // Disable ANSI colors in log4rs output
@@ -59,6 +60,7 @@ pub fn load_logger() {
     let rss_info_appender = create_file_appender(RSS_INFO_SCHEDULER);
     let rss_fetch_and_notification_scheduler =
         create_file_appender(RSS_FETCH_AND_NOTIFICATION_SCHEDULER);
+    let recommendation_appender = create_file_appender(RECOMMENDATION_SCHEDULER);
 
     // 기본 서버 로그용 파일 어펜더
     let server_appender = create_file_appender("server");
@@ -73,7 +75,10 @@ pub fn load_logger() {
         .appender(Appender::builder().build(
             "rss_fetch_and_notification_file",
             Box::new(rss_fetch_and_notification_scheduler),
-        ));
+        ))
+        .appender(
+            Appender::builder().build("recommendation_file", Box::new(recommendation_appender)),
+        );
 
     // 스케줄러별 로거 설정
     config_builder = config_builder
@@ -96,6 +101,11 @@ pub fn load_logger() {
             Logger::builder()
                 .appender("rss_fetch_and_notification_file")
                 .build(RSS_FETCH_AND_NOTIFICATION_SCHEDULER, LevelFilter::Info),
+        )
+        .logger(
+            Logger::builder()
+                .appender("recommendation_file")
+                .build(RECOMMENDATION_SCHEDULER, LevelFilter::Info),
         );
 
     // 루트 로거 설정
@@ -195,3 +205,24 @@ macro_rules! rss_fetch_and_notification_error {
         log::error!(target: "rss_fetch_and_notification_scheduler", $($arg)+)
     };
 }
+
+#[macro_export]
+macro_rules! recommendation_info {
+    ($($arg:tt)+) => {
+        log::info!(target: "recommendation_scheduler", $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! recommendation_warn {
+    ($($arg:tt)+) => {
+        log::warn!(target: "recommendation_scheduler", $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! recommendation_error {
+    ($($arg:tt)+) => {
+        log::error!(target: "recommendation_scheduler", $($arg)+)
+    };
+}
@@ -0,0 +1,19 @@
+use rocket::{http::Status, serde::json::Json, State};
+use sqlx::MySqlPool;
+
+use crate::{model::llm_usage::LlmUsageReport, service::llm_usage_service};
+
+/// 최근 `days`일(기본 30일)의 LLM 사용량과 예산 현황을 반환함.
+#[get("/llm/usage?<days>")]
+pub async fn get_llm_usage(
+    pool: &State<MySqlPool>,
+    days: Option<i64>,
+) -> Result<Json<LlmUsageReport>, Status> {
+    match llm_usage_service::get_usage_report(pool.inner(), days.unwrap_or(30)).await {
+        Ok(report) => Ok(Json(report)),
+        Err(e) => {
+            error!("[Handler] Failed to get llm usage report: {:?}", e);
+            Err(Status::InternalServerError)
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod llm_usage_handler;
+pub mod search_handler;
+
+use rocket::Route;
+
+pub fn routes() -> Vec<Route> {
+    routes![
+        llm_usage_handler::get_llm_usage,
+        search_handler::search,
+        search_handler::get_similar,
+    ]
+}
@@ -0,0 +1,167 @@
+use chrono::NaiveDate;
+use rocket::{http::Status, serde::json::Json, State};
+use sqlx::MySqlPool;
+
+use crate::{
+    model::{
+        error::OmniNewsError,
+        rss::NewticleType,
+        search::{SearchFilter, SearchHit},
+    },
+    service::search_service,
+    utils::{embedding_util::EmbeddingService, index_searcher::IndexSearcher},
+};
+
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 50;
+
+/// 자유 텍스트와 비슷한 `kind`(`channel`, `rss`, `news`, 기본 `news`) 항목.
+/// `lang`은 언어 접두사, `since`/`until`은 `YYYY-MM-DD`이며 `until` 날짜까지 포함함.
+#[get("/search?<q>&<kind>&<lang>&<since>&<until>&<limit>")]
+#[allow(clippy::too_many_arguments)]
+pub async fn search(
+    pool: &State<MySqlPool>,
+    searcher: &State<IndexSearcher>,
+    embedding_service: &State<EmbeddingService>,
+    q: &str,
+    kind: Option<&str>,
+    lang: Option<&str>,
+    since: Option<&str>,
+    until: Option<&str>,
+    limit: Option<usize>,
+) -> Result<Json<Vec<SearchHit>>, Status> {
+    if q.trim().is_empty() {
+        return Err(Status::BadRequest);
+    }
+    let category = parse_kind(kind.unwrap_or("news"))?;
+    let filter = parse_filter(lang, since, until)?;
+
+    search_service::search_by_text(
+        pool.inner(),
+        searcher.inner(),
+        embedding_service.inner(),
+        &category,
+        q.trim(),
+        &filter,
+        clamp_limit(limit),
+    )
+    .await
+    .map(Json)
+    .map_err(|e| error_status("search", e))
+}
+
+/// `kind`(`channel`, `rss`, `news`) 항목 `id`와 비슷한 같은 종류의 항목.
+#[get("/search/<kind>/<id>/similar?<lang>&<since>&<until>&<limit>")]
+#[allow(clippy::too_many_arguments)]
+pub async fn get_similar(
+    pool: &State<MySqlPool>,
+    searcher: &State<IndexSearcher>,
+    kind: &str,
+    id: i32,
+    lang: Option<&str>,
+    since: Option<&str>,
+    until: Option<&str>,
+    limit: Option<usize>,
+) -> Result<Json<Vec<SearchHit>>, Status> {
+    let category = parse_kind(kind)?;
+    let filter = parse_filter(lang, since, until)?;
+
+    search_service::find_similar(
+        pool.inner(),
+        searcher.inner(),
+        &category,
+        id,
+        &filter,
+        clamp_limit(limit),
+    )
+    .await
+    .map(Json)
+    .map_err(|e| error_status("find similar items", e))
+}
+
+fn parse_kind(kind: &str) -> Result<NewticleType, Status> {
+    NewticleType::parse(kind).ok_or(Status::BadRequest)
+}
+
+fn parse_filter(
+    lang: Option<&str>,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Result<SearchFilter, Status> {
+    let parse_date = |date: Option<&str>| {
+        date.map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d"))
+            .transpose()
+            .map_err(|_| Status::BadRequest)
+    };
+
+    Ok(SearchFilter {
+        language: lang
+            .map(str::trim)
+            .filter(|lang| !lang.is_empty())
+            .map(str::to_string),
+        since: parse_date(since)?.and_then(|date| date.and_hms_opt(0, 0, 0)),
+        // 날짜 단위로 받으므로 다음 날 0시 전까지 포함함
+        until: parse_date(until)?
+            .and_then(|date| date.succ_opt())
+            .and_then(|date| date.and_hms_opt(0, 0, 0)),
+    })
+}
+
+fn clamp_limit(limit: Option<usize>) -> usize {
+    limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+}
+
+fn error_status(action: &str, e: OmniNewsError) -> Status {
+    match e {
+        OmniNewsError::NotFound(target) => {
+            warn!("[Handler] Failed to {}, not found: {}", action, target);
+            Status::NotFound
+        }
+        e => {
+            error!("[Handler] Failed to {}: {:?}", action, e);
+            Status::InternalServerError
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_filter_with_inclusive_until_date() {
+        let filter = parse_filter(Some(" ko "), Some("2025-10-01"), Some("2025-10-31")).unwrap();
+
+        assert_eq!(filter.language.as_deref(), Some("ko"));
+        assert_eq!(
+            filter.since,
+            NaiveDate::from_ymd_opt(2025, 10, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+        );
+        // until 날짜 하루 전체를 포함하도록 다음 날 0시가 됨
+        assert_eq!(
+            filter.until,
+            NaiveDate::from_ymd_opt(2025, 11, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+        );
+
+        let empty = parse_filter(Some(""), None, None).unwrap();
+        assert!(empty.language.is_none());
+        assert!(empty.since.is_none() && empty.until.is_none());
+
+        assert_eq!(
+            parse_filter(None, Some("2025/10/01"), None).unwrap_err(),
+            Status::BadRequest
+        );
+    }
+
+    #[test]
+    fn clamps_limit() {
+        assert_eq!(clamp_limit(None), DEFAULT_LIMIT);
+        assert_eq!(clamp_limit(Some(0)), 1);
+        assert_eq!(clamp_limit(Some(20)), 20);
+        assert_eq!(clamp_limit(Some(1000)), MAX_LIMIT);
+    }
+}
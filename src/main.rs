@@ -4,6 +4,7 @@ extern crate rocket;
 mod config;
 mod db_util;
 mod global;
+mod handler;
 mod model;
 mod repository;
 mod scheduler;
@@ -15,7 +16,9 @@ use std::time::Duration;
 use config::{env, logging};
 use sqlx::MySqlPool;
 use tokio::time::sleep;
-use utils::embedding_util::EmbeddingService;
+use utils::{
+    embedding_util::EmbeddingService, index_searcher::IndexSearcher, summarizer::SummarizerChain,
+};
 
 use crate::config::webdriver::{DriverPool, DriverPoolConfig};
 
@@ -25,35 +28,90 @@ async fn main() {
     logging::load_logger();
 
     let pool = db_util::create_pool().await;
+
+    // `eval-index`: 서버와 스케줄러 없이 인덱스 품질 평가만 실행하고 종료
+    if std::env::args().nth(1).as_deref() == Some("eval-index") {
+        if let Err(e) = service::index_evaluation_service::evaluate_indexes(&pool).await {
+            error!("[Eval] Failed to evaluate indexes: {:?}", e);
+        }
+        return;
+    }
+
     let embedding_service = EmbeddingService::new();
+    let summarizer = SummarizerChain::from_env();
     let dp_cfg = DriverPoolConfig::default();
     let driver_pool = DriverPool::new(dp_cfg);
+    let index_searcher = IndexSearcher::new();
+
+    tokio::join!(
+        start_server(
+            pool.clone(),
+            embedding_service.clone(),
+            index_searcher.clone()
+        ),
+        start_scheduler(
+            &pool,
+            &embedding_service,
+            &summarizer,
+            &driver_pool,
+            &index_searcher
+        ),
+    );
+}
+
+async fn start_server(
+    pool: MySqlPool,
+    embedding_service: EmbeddingService,
+    index_searcher: IndexSearcher,
+) {
+    let result = rocket::build()
+        .manage(pool)
+        .manage(embedding_service)
+        .manage(index_searcher)
+        .mount("/api/v1", handler::routes())
+        .launch()
+        .await;
 
-    start_scheduler(&pool, &embedding_service, &driver_pool).await;
+    if let Err(e) = result {
+        error!("[Server] Failed to launch server: {:?}", e);
+    }
 }
 
 async fn start_scheduler(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    summarizer: &SummarizerChain,
     driver_pool: &DriverPool,
+    index_searcher: &IndexSearcher,
 ) {
     use scheduler::{
-        annoy_scheduler::*, news_scheduler::*, rss_info_update_scheduler::*,
-        rss_notification_scheduler::*,
+        annoy_scheduler::*, embedding_migration_scheduler::*, integrity_scheduler::*,
+        news_scheduler::*, recommendation_scheduler::*, retention_scheduler::*,
+        rss_analysis_scheduler::*, rss_info_update_scheduler::*, rss_notification_scheduler::*,
     };
     sleep(Duration::from_secs(10)).await; // 서버 시작 후 10초 대기
 
     tokio::join!(
-        // 매일 자정 1주 전 뉴스 삭제
-        delete_old_news_scheduler(pool),
-        // 5분마다 뉴스 패치
-        fetch_news_scheduler(pool),
-        // 1시간마다 Annoy 인덱스 저장
+        // 매일 자정 보존 정책에 따라 오래된 뉴스/RSS 아이템과 임베딩 삭제
+        retention_scheduler(pool),
+        // 뉴스 소스별 설정된 주기마다 뉴스 패치
+        fetch_news_scheduler(pool, embedding_service, summarizer),
+        // 1시간마다 임베딩이 없는 뉴스 임베딩 생성
+        backfill_news_embedding_scheduler(pool, embedding_service),
+        // 1시간마다 삭제된 뉴스/RSS 아이템을 가리키는 임베딩과 클러스터 정리
+        integrity_scheduler(pool),
+        // 10분마다 임베딩 변경 확인 후 바뀐 Annoy 인덱스만 다시 저장
         save_annoy_scheduler(pool),
+        // 10분마다 임베딩 모델 버전 확인, 바뀌었으면 재임베딩 후 인덱스 전환
+        embedding_migration_scheduler(pool, embedding_service),
         // TODO: 아래 두개는 잘되는지 검증은 실사용 해보면서 하기.
         // 10분마다 RSS 아이템 패치 및 채널 구독자에게 알림
         rss_fetch_and_notification_scheduler(pool, embedding_service, driver_pool),
+        // 10분마다 분석되지 않은 RSS 아이템 요약/키워드/카테고리 분석
+        rss_analysis_scheduler(pool, summarizer),
         // 매일 RSS 채널 정보 업데이트
         rss_info_update_scheduler(pool, embedding_service, driver_pool),
+        // 매일 KST 8시 구독 채널과 읽은 항목으로 사용자별 추천 목록 생성
+        recommendation_scheduler(pool, index_searcher),
     );
 }
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+// LLM이 분류할 수 있는 카테고리. 네이버 섹션 이름과 맞춤.
+pub const ANALYSIS_CATEGORIES: &[&str] = &[
+    "정치",
+    "경제",
+    "사회",
+    "생활/문화",
+    "세계",
+    "IT/과학",
+    "연예",
+    "스포츠",
+    "기타",
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Sentiment {
+    Positive,
+    #[default]
+    Neutral,
+    Negative,
+}
+
+impl Sentiment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sentiment::Positive => "positive",
+            Sentiment::Neutral => "neutral",
+            Sentiment::Negative => "negative",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedEntity {
+    pub name: String,
+    // person, organization, location, etc.
+    #[serde(rename = "type")]
+    pub entity_type: String,
+}
+
+/// LLM 한 번의 호출로 얻는 요약, 키워드, 카테고리, 개체명, 감성.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArticleAnalysis {
+    pub summary: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub entities: Vec<NamedEntity>,
+    #[serde(default)]
+    pub sentiment: Sentiment,
+}
+
+impl ArticleAnalysis {
+    pub fn keywords_json(&self) -> Option<String> {
+        if self.keywords.is_empty() {
+            return None;
+        }
+        serde_json::to_string(&self.keywords).ok()
+    }
+
+    pub fn entities_json(&self) -> Option<String> {
+        if self.entities.is_empty() {
+            return None;
+        }
+        serde_json::to_string(&self.entities).ok()
+    }
+
+    pub fn category(&self) -> Option<String> {
+        Some(self.category.clone()).filter(|c| !c.is_empty())
+    }
+}
+
+/// 모델이 반환한 카테고리를 `ANALYSIS_CATEGORIES` 중 하나로 정규화함.
+pub fn normalize_category(raw: &str) -> Option<&'static str> {
+    let key = raw.trim().to_lowercase().replace([' ', '·', '_'], "");
+    if let Some(category) = ANALYSIS_CATEGORIES
+        .iter()
+        .find(|c| c.to_lowercase().replace(' ', "") == key)
+    {
+        return Some(category);
+    }
+
+    let category = match key.as_str() {
+        "politics" => "정치",
+        "economy" | "business" | "finance" | "경제/금융" => "경제",
+        "society" | "사회일반" => "사회",
+        "culture" | "life" | "lifestyle" | "생활" | "문화" | "생활문화" => "생활/문화",
+        "world" | "international" | "국제" => "세계",
+        "it" | "science" | "tech" | "technology" | "it과학" | "과학" => "IT/과학",
+        "entertainment" => "연예",
+        "sports" | "sport" => "스포츠",
+        "other" | "etc" | "misc" => "기타",
+        _ => return None,
+    };
+    Some(category)
+}
@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
@@ -8,6 +9,9 @@ pub struct NewEmbedding {
     pub rss_id: Option<i32>,
     pub news_id: Option<i32>,
     pub embedding_source_rank: Option<i32>,
+    pub embedding_model_id: Option<String>,
+    pub embedding_model_version: Option<i32>,
+    pub embedding_content_hash: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -18,4 +22,27 @@ pub struct Embedding {
     pub rss_id: Option<i32>,
     pub news_id: Option<i32>,
     pub embedding_source_rank: Option<i32>,
+    pub embedding_model_id: Option<String>,
+    pub embedding_model_version: Option<i32>,
+    pub embedding_content_hash: Option<String>,
+}
+
+/// 임베딩 모델 버전. `active` 버전의 임베딩으로 Annoy 인덱스를 만들고,
+/// 새 버전은 재임베딩이 끝날 때까지 `migrating` 상태로 남음.
+#[derive(Debug, Clone)]
+pub struct EmbeddingModel {
+    pub embedding_model_version: Option<i32>,
+    pub embedding_model_id: Option<String>,
+    pub embedding_model_dimension: Option<i32>,
+}
+
+/// 인덱스 종류별 임베딩 현황. `new_rows`는 `embedding_id`가 이전 빌드의 최대 ID보다 큰 행,
+/// `updated_rows`는 이전 빌드에 포함된 ID 중 이전 빌드 이후에 갱신된 행의 수.
+#[derive(Debug, Clone, Default)]
+pub struct EmbeddingIndexStats {
+    pub row_count: i64,
+    pub max_embedding_id: Option<i32>,
+    pub max_updated_at: Option<NaiveDateTime>,
+    pub new_rows: i64,
+    pub updated_rows: i64,
 }
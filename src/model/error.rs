@@ -1,6 +1,8 @@
 use thirtyfour::error::WebDriverError;
 use thiserror::Error;
 
+use super::llm_usage::DiscardedUsage;
+
 #[derive(Debug, Error)]
 pub enum OmniNewsError {
     #[error("Failed to fetch : {0}")]
@@ -47,6 +49,15 @@ pub enum OmniNewsError {
 
     #[error("Fetch error")]
     FetchError,
+
+    #[error("LLM error: {0}")]
+    Llm(#[from] LlmError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to publish index: {0}")]
+    Publish(String),
 }
 
 #[derive(Debug, Error)]
@@ -58,3 +69,26 @@ pub enum PoolError {
     #[error("Timeout while waiting for a driver")]
     Timeout,
 }
+
+#[derive(Debug, Error)]
+pub enum LlmError {
+    #[error("API key not set: {0}")]
+    MissingApiKey(String),
+    #[error("Config not set: {0}")]
+    MissingConfig(String),
+    #[error("Request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Unexpected status {0}: {1}")]
+    Status(u16, String),
+    #[error("Empty response")]
+    EmptyResponse,
+    // 분석 결과 파싱/검증 실패. provider는 토큰 사용량을 함께 담아 `UnparsableOutput`으로 반환함.
+    #[error("Invalid output: {0}")]
+    InvalidOutput(String),
+    // 응답은 받았지만 분석 결과로 변환하지 못한 경우. 사용한 토큰은 비용 원장에 기록해야 함.
+    #[error("Unparsable output: {0}")]
+    UnparsableOutput(String, DiscardedUsage),
+    // 모든 provider가 실패함. 그동안 버려진 응답의 사용량을 함께 반환함.
+    #[error("All summarizer providers failed")]
+    AllProvidersFailed(Vec<DiscardedUsage>),
+}
@@ -0,0 +1,16 @@
+use serde::Serialize;
+
+/// `eval-index` 결과 한 행. 인덱스 설정 하나에 대한 빌드 비용과 검색 품질.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexEvaluation {
+    pub index: String,
+    pub params: String,
+    pub items: usize,
+    // 항목 추가부터 파일 저장까지 걸린 시간
+    pub build_ms: u64,
+    pub index_bytes: u64,
+    // 브루트포스 코사인 top-k 대비 recall@k (쿼리 평균)
+    pub recall: f64,
+    pub mean_query_us: f64,
+    pub p95_query_us: f64,
+}
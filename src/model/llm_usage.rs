@@ -0,0 +1,57 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenUsage {
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+}
+
+/// 검증에 실패해 버려진 응답의 토큰 사용량. 비용 원장에는 함께 기록함.
+#[derive(Debug, Clone)]
+pub struct DiscardedUsage {
+    pub provider: String,
+    pub model: String,
+    pub usage: TokenUsage,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewLlmUsage {
+    pub usage_date: NaiveDate,
+    pub provider: String,
+    pub model: String,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub estimated_cost: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LlmUsage {
+    pub usage_date: Option<NaiveDate>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub request_count: Option<i32>,
+    pub input_tokens: Option<i64>,
+    pub output_tokens: Option<i64>,
+    pub estimated_cost: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum BudgetLevel {
+    // 예산 여유 있음. 기본 모델 사용
+    Normal,
+    // 예산 소진 임박. 저렴한 모델 사용
+    Economy,
+    // 예산 소진. LLM 호출 없이 원문 설명 사용
+    Exhausted,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LlmUsageReport {
+    pub budget_level: BudgetLevel,
+    pub daily_budget: f64,
+    pub monthly_budget: f64,
+    pub today_cost: f64,
+    pub month_cost: f64,
+    pub usages: Vec<LlmUsage>,
+}
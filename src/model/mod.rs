@@ -1,5 +1,12 @@
+pub mod analysis;
 pub mod embedding;
 pub mod error;
 pub mod fcm_token;
+pub mod index_evaluation;
+pub mod llm_usage;
 pub mod news;
+pub mod recommendation;
+pub mod retention;
 pub mod rss;
+pub mod search;
+pub mod summary_cache;
@@ -1,6 +1,8 @@
 use chrono::NaiveDateTime;
 
-#[derive(Debug, Clone)]
+use super::analysis::ArticleAnalysis;
+
+#[derive(Debug, Clone, Default)]
 pub struct NewNews {
     pub news_title: Option<String>,
     pub news_description: Option<String>,
@@ -10,4 +12,46 @@ pub struct NewNews {
     pub news_pub_date: Option<NaiveDateTime>,
     pub news_image_link: Option<String>,
     pub news_category: Option<String>,
+    pub news_cluster_id: Option<i32>,
+    // 아래는 LLM 분석 결과. 키워드와 개체명은 JSON 배열 문자열.
+    pub news_keywords: Option<String>,
+    pub news_entities: Option<String>,
+    pub news_sentiment: Option<String>,
+    pub news_analysis_category: Option<String>,
+    // 클러스터 비교용 제목 + 설명 임베딩과 모델 버전
+    pub news_cluster_embedding: Option<Vec<u8>>,
+    pub news_cluster_embedding_version: Option<i32>,
+}
+
+impl NewNews {
+    pub fn apply_analysis(&mut self, analysis: &ArticleAnalysis) {
+        self.news_summary = Some(analysis.summary.clone());
+        self.news_keywords = analysis.keywords_json();
+        self.news_entities = analysis.entities_json();
+        self.news_analysis_category = analysis.category();
+        // 추출 요약은 감성을 분석하지 않음
+        if analysis.category().is_some() {
+            self.news_sentiment = Some(analysis.sentiment.as_str().to_string());
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecentNewsEmbedding {
+    pub news_id: Option<i32>,
+    pub news_cluster_id: Option<i32>,
+    pub news_summary: Option<String>,
+    pub news_keywords: Option<String>,
+    pub news_entities: Option<String>,
+    pub news_sentiment: Option<String>,
+    pub news_analysis_category: Option<String>,
+    pub embedding_value: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewsEmbeddingSource {
+    pub news_id: Option<i32>,
+    pub news_title: Option<String>,
+    pub news_description: Option<String>,
+    pub news_summary: Option<String>,
 }
@@ -0,0 +1,38 @@
+/// 구독 채널이나 최근 읽은 항목이 있어 추천을 만들 사용자.
+#[derive(Debug, Clone)]
+pub struct RecommendationUser {
+    pub user_id: i32,
+}
+
+/// 구독 채널과 그 채널의 임베딩. 임베딩이 없는 채널은 `embedding_value`가 `None`.
+#[derive(Debug, Clone)]
+pub struct SubscribedChannelEmbedding {
+    pub channel_id: Option<i32>,
+    pub embedding_value: Option<Vec<u8>>,
+}
+
+/// 사용자가 읽은 RSS 아이템 또는 뉴스의 임베딩.
+#[derive(Debug, Clone)]
+pub struct ReadEmbedding {
+    pub rss_id: Option<i32>,
+    pub news_id: Option<i32>,
+    pub embedding_value: Option<Vec<u8>>,
+}
+
+/// 추천 후보. RSS 아이템과 뉴스 중 하나이며, `score`는 관심사 벡터와의 코사인 유사도.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecommendationCandidate {
+    pub rss_id: Option<i32>,
+    pub news_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub title: Option<String>,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewRecommendation {
+    pub recommendation_rank: i32,
+    pub rss_id: Option<i32>,
+    pub news_id: Option<i32>,
+    pub recommendation_score: f64,
+}
@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionTable {
+    News,
+    RssItem,
+}
+
+impl RetentionTable {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetentionTable::News => "news",
+            RetentionTable::RssItem => "rss_item",
+        }
+    }
+}
+
+/// `retention_policies.json`의 항목 하나.
+/// `category`가 없으면 같은 테이블에서 카테고리별 정책이 없는 나머지 행에 적용됨.
+/// 뉴스는 `news_category`, RSS 아이템은 분석 카테고리(`rss_analysis_category`)를 기준으로 함.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetentionPolicy {
+    pub table: RetentionTable,
+    #[serde(default)]
+    pub category: Option<String>,
+    pub retention_days: i64,
+    // 삭제 전에 gzip 압축 JSONL 파일로 보관할지 여부
+    #[serde(default)]
+    pub archive: bool,
+}
+
+/// 정책 하나를 적용할 때 사용하는 조건.
+/// `excluded_categories`는 카테고리별 정책이 따로 있는 카테고리의 JSON 배열 문자열.
+#[derive(Debug, Clone)]
+pub struct RetentionFilter {
+    pub cutoff: NaiveDateTime,
+    pub category: Option<String>,
+    pub excluded_categories: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExpiredRow {
+    pub id: i32,
+    // 행 전체를 담은 JSON 문자열
+    pub row_json: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RetentionReport {
+    pub table: &'static str,
+    pub category: Option<String>,
+    pub cutoff: NaiveDateTime,
+    pub matched: i64,
+    pub archived: usize,
+    pub deleted: u64,
+    pub deleted_embeddings: u64,
+}
@@ -1,13 +1,34 @@
 use chrono::NaiveDateTime;
 use rss::Item;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NewticleType {
     Channel,
     Rss,
     News,
 }
 
+impl NewticleType {
+    /// `channel`, `rss`, `news`.
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "channel" => Some(Self::Channel),
+            "rss" => Some(Self::Rss),
+            "news" => Some(Self::News),
+            _ => None,
+        }
+    }
+
+    /// `parse`의 반대. 종류별 임베딩 조회에 사용함.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Channel => "channel",
+            Self::Rss => "rss",
+            Self::News => "news",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NewRssChannel {
     pub channel_title: Option<String>,
@@ -46,6 +67,28 @@ pub struct NewRssItem {
     pub rss_image_link: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct RssItemAnalysisSource {
+    pub rss_id: Option<i32>,
+    pub rss_title: Option<String>,
+    pub rss_description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RssChannelEmbeddingSource {
+    pub channel_id: Option<i32>,
+    pub channel_title: Option<String>,
+    pub channel_description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RssItemEmbeddingSource {
+    pub rss_id: Option<i32>,
+    pub rss_title: Option<String>,
+    pub rss_description: Option<String>,
+    pub rss_author: Option<String>,
+}
+
 #[allow(clippy::too_many_arguments)]
 impl NewRssChannel {
     pub fn new(
@@ -0,0 +1,76 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// 검색 결과 필터. 언어는 채널 언어의 접두사로 비교하고(`ko`는 `ko-KR`도 포함),
+/// 날짜는 RSS 아이템과 뉴스의 발행 시각에만 적용됨.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    pub language: Option<String>,
+    // 이 시각 이후(포함)
+    pub since: Option<NaiveDateTime>,
+    // 이 시각 이전(미포함)
+    pub until: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelSearchResult {
+    // 인덱스 검색 순서로 되돌리기 위한 키. 응답에는 포함하지 않음
+    #[serde(skip)]
+    pub embedding_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub channel_title: Option<String>,
+    pub channel_link: Option<String>,
+    pub channel_description: Option<String>,
+    pub channel_image_url: Option<String>,
+    pub channel_language: Option<String>,
+    pub channel_rss_link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RssItemSearchResult {
+    // 인덱스 검색 순서로 되돌리기 위한 키. 응답에는 포함하지 않음
+    #[serde(skip)]
+    pub embedding_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub channel_title: Option<String>,
+    pub channel_language: Option<String>,
+    pub rss_title: Option<String>,
+    pub rss_description: Option<String>,
+    pub rss_link: Option<String>,
+    pub rss_author: Option<String>,
+    pub rss_pub_date: Option<NaiveDateTime>,
+    pub rss_image_link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NewsSearchResult {
+    // 인덱스 검색 순서로 되돌리기 위한 키. 응답에는 포함하지 않음
+    #[serde(skip)]
+    pub embedding_id: Option<i32>,
+    pub news_id: Option<i32>,
+    pub news_title: Option<String>,
+    pub news_description: Option<String>,
+    pub news_summary: Option<String>,
+    pub news_link: Option<String>,
+    pub news_source: Option<String>,
+    pub news_pub_date: Option<NaiveDateTime>,
+    pub news_image_link: Option<String>,
+    pub news_category: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SearchItem {
+    Channel(ChannelSearchResult),
+    Rss(RssItemSearchResult),
+    News(NewsSearchResult),
+}
+
+/// 검색 결과 한 건. `score`는 쿼리와의 코사인 유사도.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub score: f32,
+    #[serde(flatten)]
+    pub item: SearchItem,
+}
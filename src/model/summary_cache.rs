@@ -0,0 +1,18 @@
+#[derive(Debug, Clone)]
+pub struct NewSummaryCache {
+    pub content_hash: String,
+    pub prompt_version: i32,
+    pub model: String,
+    pub summary: String,
+    // ArticleAnalysis JSON
+    pub analysis: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SummaryCache {
+    pub content_hash: Option<String>,
+    pub prompt_version: Option<i32>,
+    pub model: Option<String>,
+    pub summary: Option<String>,
+    pub analysis: Option<String>,
+}
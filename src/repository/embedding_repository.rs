@@ -1,8 +1,12 @@
+use chrono::NaiveDateTime;
 use sqlx::{query, query_as, MySqlPool};
 
 use crate::{
     db_util::get_db,
-    model::embedding::{Embedding, NewEmbedding},
+    model::{
+        embedding::{Embedding, EmbeddingIndexStats, EmbeddingModel, NewEmbedding},
+        rss::{RssChannelEmbeddingSource, RssItemEmbeddingSource},
+    },
 };
 
 pub async fn insert_embedding(
@@ -12,12 +16,15 @@ pub async fn insert_embedding(
     let mut conn = get_db(pool).await?;
 
     let result = query!(
-    "INSERT INTO embedding (embedding_value, channel_id, rss_id, news_id, embedding_source_rank) VALUES (?, ?, ?, ?, ?);",
+    "INSERT INTO embedding (embedding_value, channel_id, rss_id, news_id, embedding_source_rank, embedding_model_id, embedding_model_version, embedding_content_hash) VALUES (?, ?, ?, ?, ?, ?, ?, ?);",
         embedding.embedding_value,
         embedding.channel_id,
         embedding.rss_id,
         embedding.news_id,
         embedding.embedding_source_rank,
+        embedding.embedding_model_id,
+        embedding.embedding_model_version,
+        embedding.embedding_content_hash,
     )
     .execute(&mut *conn)
     .await;
@@ -36,10 +43,12 @@ pub async fn update_embedding(
 
     let result = query!(
         "UPDATE embedding 
-    SET embedding_value = ?
-    WHERE channel_id = ?;",
+    SET embedding_value = ?, embedding_content_hash = ?
+    WHERE channel_id = ? AND embedding_model_version = ?;",
         embedding.embedding_value,
+        embedding.embedding_content_hash,
         embedding.channel_id,
+        embedding.embedding_model_version,
     )
     .execute(&mut *conn)
     .await;
@@ -50,15 +59,371 @@ pub async fn update_embedding(
     }
 }
 
-pub async fn select_all_channel_embeddings(
+/// 같은 모델 버전에서 같은 텍스트로 만든 임베딩 벡터. 없으면 `None`.
+pub async fn select_embedding_value_by_content_hash(
     pool: &MySqlPool,
+    embedding_content_hash: &str,
+    embedding_model_version: i32,
+) -> Result<Option<Vec<u8>>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        SELECT embedding_value FROM embedding
+        WHERE embedding_content_hash = ? AND embedding_model_version = ?
+        LIMIT 1
+        "#,
+        embedding_content_hash,
+        embedding_model_version,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.and_then(|row| row.embedding_value)),
+        Err(e) => Err(e),
+    }
+}
+
+/// 채널 임베딩을 만들 때 사용한 텍스트의 해시. 임베딩이 없거나 해시가 기록되지 않았으면 `None`.
+pub async fn select_channel_embedding_content_hash(
+    pool: &MySqlPool,
+    channel_id: i32,
+    embedding_model_version: i32,
+) -> Result<Option<String>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        SELECT embedding_content_hash FROM embedding
+        WHERE channel_id = ? AND rss_id IS NULL AND news_id IS NULL
+            AND embedding_model_version = ?
+        LIMIT 1
+        "#,
+        channel_id,
+        embedding_model_version,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.and_then(|row| row.embedding_content_hash)),
+        Err(e) => Err(e),
+    }
+}
+
+// 인덱스 종류(`kind`: `channel`, `rss`, `news`)별 조회는 아래 조건으로 같은 쿼리를 사용함.
+// RSS 아이템과 뉴스는 원본 행이 남아 있는 임베딩만 포함함.
+
+/// 인덱스에 들어갈 `kind` 종류의 임베딩 현황. `last_embedding_id`, `last_updated_at`은 이전 빌드의 워터마크.
+pub async fn select_embedding_index_stats(
+    pool: &MySqlPool,
+    kind: &str,
+    embedding_model_version: i32,
+    last_embedding_id: i32,
+    last_updated_at: Option<NaiveDateTime>,
+) -> Result<EmbeddingIndexStats, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        EmbeddingIndexStats,
+        r#"
+        SELECT COUNT(*) AS row_count,
+            MAX(embedding_id) AS max_embedding_id,
+            MAX(embedding_updated_at) AS max_updated_at,
+            COUNT(CASE WHEN embedding_id > ? THEN 1 END) AS new_rows,
+            COUNT(CASE WHEN embedding_id <= ? AND embedding_updated_at > ? THEN 1 END) AS updated_rows
+        FROM embedding
+        WHERE embedding_model_version = ?
+            AND (
+                (? = 'channel' AND channel_id IS NOT NULL AND rss_id IS NULL AND news_id IS NULL)
+                OR (? = 'rss' AND channel_id IS NULL AND rss_id IS NOT NULL AND news_id IS NULL
+                    AND EXISTS (SELECT 1 FROM rss_item WHERE rss_item.rss_id = embedding.rss_id))
+                OR (? = 'news' AND channel_id IS NULL AND rss_id IS NULL AND news_id IS NOT NULL
+                    AND EXISTS (SELECT 1 FROM news WHERE news.news_id = embedding.news_id))
+            )
+        "#,
+        last_embedding_id,
+        last_embedding_id,
+        last_updated_at,
+        embedding_model_version,
+        kind,
+        kind,
+        kind,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `kind` 종류에서 `embedding_id`가 `after_id`보다 크고 `max_id` 이하인 임베딩을 ID순으로 `limit`개 조회함.
+/// `changed_after_id`보다 ID가 크거나 `changed_after` 이후에 갱신된 행만 조회하며, 전체를 읽을 때는 `0`, `None`을 넘김.
+#[allow(clippy::too_many_arguments)]
+pub async fn select_embedding_page(
+    pool: &MySqlPool,
+    kind: &str,
+    embedding_model_version: i32,
+    after_id: i32,
+    max_id: i32,
+    changed_after_id: i32,
+    changed_after: Option<NaiveDateTime>,
+    limit: i64,
 ) -> Result<Vec<Embedding>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = query_as!(
-    Embedding,
-        "SELECT * from embedding WHERE channel_id IS NOT NULL AND rss_id IS NULL AND news_id IS NULL",
+        Embedding,
+        r#"
+        SELECT embedding_id, embedding_value, channel_id, rss_id, news_id, embedding_source_rank,
+            embedding_model_id, embedding_model_version, embedding_content_hash
+        FROM embedding
+        WHERE embedding_model_version = ?
+            AND (
+                (? = 'channel' AND channel_id IS NOT NULL AND rss_id IS NULL AND news_id IS NULL)
+                OR (? = 'rss' AND channel_id IS NULL AND rss_id IS NOT NULL AND news_id IS NULL
+                    AND EXISTS (SELECT 1 FROM rss_item WHERE rss_item.rss_id = embedding.rss_id))
+                OR (? = 'news' AND channel_id IS NULL AND rss_id IS NULL AND news_id IS NOT NULL
+                    AND EXISTS (SELECT 1 FROM news WHERE news.news_id = embedding.news_id))
+            )
+            AND embedding_id > ? AND embedding_id <= ?
+            AND (embedding_id > ? OR embedding_updated_at > ?)
+        ORDER BY embedding_id
+        LIMIT ?
+        "#,
+        embedding_model_version,
+        kind,
+        kind,
+        kind,
+        after_id,
+        max_id,
+        changed_after_id,
+        changed_after,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 인덱스에서 삭제된 항목을 찾기 위해 `kind` 종류의 `embedding_id`만 ID순으로 `limit`개 조회함.
+pub async fn select_embedding_ids(
+    pool: &MySqlPool,
+    kind: &str,
+    embedding_model_version: i32,
+    after_id: i32,
+    limit: i64,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        r#"
+        SELECT embedding_id
+        FROM embedding
+        WHERE embedding_model_version = ?
+            AND (
+                (? = 'channel' AND channel_id IS NOT NULL AND rss_id IS NULL AND news_id IS NULL)
+                OR (? = 'rss' AND channel_id IS NULL AND rss_id IS NOT NULL AND news_id IS NULL
+                    AND EXISTS (SELECT 1 FROM rss_item WHERE rss_item.rss_id = embedding.rss_id))
+                OR (? = 'news' AND channel_id IS NULL AND rss_id IS NULL AND news_id IS NOT NULL
+                    AND EXISTS (SELECT 1 FROM news WHERE news.news_id = embedding.news_id))
+            )
+            AND embedding_id > ?
+        ORDER BY embedding_id
+        LIMIT ?
+        "#,
+        embedding_model_version,
+        kind,
+        kind,
+        kind,
+        after_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().map(|row| row.embedding_id).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_channel_embedding(
+    pool: &MySqlPool,
+    channel_id: i32,
+    embedding_model_version: i32,
+) -> Result<Option<Embedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        Embedding,
+        r#"
+        SELECT embedding_id, embedding_value, channel_id, rss_id, news_id, embedding_source_rank,
+            embedding_model_id, embedding_model_version, embedding_content_hash
+        FROM embedding
+        WHERE channel_id = ? AND rss_id IS NULL AND news_id IS NULL
+            AND embedding_model_version = ?
+        ORDER BY embedding_source_rank, embedding_id
+        LIMIT 1
+        "#,
+        channel_id,
+        embedding_model_version,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_embedding(
+    pool: &MySqlPool,
+    rss_id: i32,
+    embedding_model_version: i32,
+) -> Result<Option<Embedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        Embedding,
+        r#"
+        SELECT embedding_id, embedding_value, channel_id, rss_id, news_id, embedding_source_rank,
+            embedding_model_id, embedding_model_version, embedding_content_hash
+        FROM embedding
+        WHERE channel_id IS NULL AND rss_id = ? AND news_id IS NULL
+            AND embedding_model_version = ?
+        ORDER BY embedding_source_rank, embedding_id
+        LIMIT 1
+        "#,
+        rss_id,
+        embedding_model_version,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_news_embedding(
+    pool: &MySqlPool,
+    news_id: i32,
+    embedding_model_version: i32,
+) -> Result<Option<Embedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        Embedding,
+        r#"
+        SELECT embedding_id, embedding_value, channel_id, rss_id, news_id, embedding_source_rank,
+            embedding_model_id, embedding_model_version, embedding_content_hash
+        FROM embedding
+        WHERE channel_id IS NULL AND rss_id IS NULL AND news_id = ?
+            AND embedding_model_version = ?
+        ORDER BY embedding_source_rank, embedding_id
+        LIMIT 1
+        "#,
+        news_id,
+        embedding_model_version,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 뉴스나 RSS 아이템이 삭제되어 연결이 끊긴 임베딩 개수.
+pub async fn count_orphan_embeddings(pool: &MySqlPool) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        SELECT COUNT(*) AS count FROM embedding
+        WHERE (news_id IS NOT NULL
+                AND NOT EXISTS (SELECT 1 FROM news WHERE news.news_id = embedding.news_id))
+            OR (rss_id IS NOT NULL
+                AND NOT EXISTS (SELECT 1 FROM rss_item WHERE rss_item.rss_id = embedding.rss_id))
+        "#
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.count),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_orphan_embeddings(pool: &MySqlPool) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE FROM embedding
+        WHERE (news_id IS NOT NULL
+                AND NOT EXISTS (SELECT 1 FROM news WHERE news.news_id = embedding.news_id))
+            OR (rss_id IS NOT NULL
+                AND NOT EXISTS (SELECT 1 FROM rss_item WHERE rss_item.rss_id = embedding.rss_id))
+        "#
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 인덱스와 검색에 사용 중인 임베딩 모델 버전.
+pub async fn select_active_embedding_model(
+    pool: &MySqlPool,
+) -> Result<Option<EmbeddingModel>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        EmbeddingModel,
+        r#"
+        SELECT embedding_model_version, embedding_model_id, embedding_model_dimension
+        FROM embedding_model
+        WHERE embedding_model_status = 'active'
+        ORDER BY activated_at DESC
+        LIMIT 1
+        "#
+    )
+    .fetch_optional(&mut *conn)
+    .await;
 
-).fetch_all(&mut *conn).await;
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_embedding_model_by_version(
+    pool: &MySqlPool,
+    embedding_model_version: i32,
+) -> Result<Option<EmbeddingModel>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        EmbeddingModel,
+        r#"
+        SELECT embedding_model_version, embedding_model_id, embedding_model_dimension
+        FROM embedding_model
+        WHERE embedding_model_version = ?
+        "#,
+        embedding_model_version,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
 
     match result {
         Ok(res) => Ok(res),
@@ -66,12 +431,117 @@ pub async fn select_all_channel_embeddings(
     }
 }
 
-pub async fn select_all_rss_embeddings(pool: &MySqlPool) -> Result<Vec<Embedding>, sqlx::Error> {
+/// 새 모델 버전을 `migrating` 상태로 등록함. 이미 등록된 버전이면 그대로 둠.
+pub async fn insert_embedding_model(
+    pool: &MySqlPool,
+    embedding_model_version: i32,
+    embedding_model_id: &str,
+    embedding_model_dimension: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        INSERT IGNORE INTO embedding_model
+            (embedding_model_version, embedding_model_id, embedding_model_dimension, embedding_model_status)
+        VALUES (?, ?, ?, 'migrating')
+        "#,
+        embedding_model_version,
+        embedding_model_id,
+        embedding_model_dimension,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 기존 `active` 버전을 `retired`로 바꾸고 주어진 버전을 `active`로 바꿈.
+/// 두 버전이 동시에 `active`이거나 하나도 없는 상태가 보이지 않도록 한 트랜잭션에서 처리함.
+pub async fn activate_embedding_model(
+    pool: &MySqlPool,
+    embedding_model_version: i32,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    query!(
+        r#"
+        UPDATE embedding_model SET embedding_model_status = 'retired'
+        WHERE embedding_model_status = 'active' AND embedding_model_version <> ?
+        "#,
+        embedding_model_version,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    query!(
+        r#"
+        UPDATE embedding_model SET embedding_model_status = 'active', activated_at = NOW()
+        WHERE embedding_model_version = ?
+        "#,
+        embedding_model_version,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+/// 주어진 버전이 아닌 임베딩을 모두 삭제함. 새 버전으로 전환한 뒤 이전 벡터를 정리할 때 사용함.
+pub async fn delete_embeddings_except_version(
+    pool: &MySqlPool,
+    embedding_model_version: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE FROM embedding
+        WHERE embedding_model_version IS NULL OR embedding_model_version <> ?
+        "#,
+        embedding_model_version,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 이전 버전 임베딩은 있지만 주어진 버전 임베딩은 없는 채널을 channel_id 순으로 가져옴.
+pub async fn select_channels_to_reembed(
+    pool: &MySqlPool,
+    embedding_model_version: i32,
+    after_channel_id: i32,
+    limit: i64,
+) -> Result<Vec<RssChannelEmbeddingSource>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
+
     let result = query_as!(
-    Embedding,
-        "SELECT * from embedding WHERE channel_id IS NULL AND rss_id IS NOT NULL AND news_id IS NULL",
-).fetch_all(&mut *conn).await;
+        RssChannelEmbeddingSource,
+        r#"
+        SELECT c.channel_id, c.channel_title, c.channel_description
+        FROM rss_channel c
+        WHERE c.channel_id > ?
+            AND EXISTS (SELECT 1 FROM embedding e
+                WHERE e.channel_id = c.channel_id AND e.rss_id IS NULL AND e.news_id IS NULL)
+            AND NOT EXISTS (SELECT 1 FROM embedding e
+                WHERE e.channel_id = c.channel_id AND e.rss_id IS NULL AND e.news_id IS NULL
+                    AND e.embedding_model_version = ?)
+        ORDER BY c.channel_id
+        LIMIT ?
+        "#,
+        after_channel_id,
+        embedding_model_version,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
 
     match result {
         Ok(res) => Ok(res),
@@ -79,12 +549,33 @@ pub async fn select_all_rss_embeddings(pool: &MySqlPool) -> Result<Vec<Embedding
     }
 }
 
-pub async fn select_all_news_embeddings(pool: &MySqlPool) -> Result<Vec<Embedding>, sqlx::Error> {
+/// 이전 버전 임베딩은 있지만 주어진 버전 임베딩은 없는 RSS 아이템을 rss_id 순으로 가져옴.
+pub async fn select_rss_items_to_reembed(
+    pool: &MySqlPool,
+    embedding_model_version: i32,
+    after_rss_id: i32,
+    limit: i64,
+) -> Result<Vec<RssItemEmbeddingSource>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
+
     let result = query_as!(
-    Embedding,
-        "SELECT * from embedding WHERE channel_id IS NULL AND rss_id IS NULL AND news_id IS NOT NULL",
-).fetch_all(&mut *conn).await;
+        RssItemEmbeddingSource,
+        r#"
+        SELECT r.rss_id, r.rss_title, r.rss_description, r.rss_author
+        FROM rss_item r
+        WHERE r.rss_id > ?
+            AND EXISTS (SELECT 1 FROM embedding e WHERE e.rss_id = r.rss_id)
+            AND NOT EXISTS (SELECT 1 FROM embedding e
+                WHERE e.rss_id = r.rss_id AND e.embedding_model_version = ?)
+        ORDER BY r.rss_id
+        LIMIT ?
+        "#,
+        after_rss_id,
+        embedding_model_version,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
 
     match result {
         Ok(res) => Ok(res),
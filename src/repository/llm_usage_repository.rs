@@ -0,0 +1,61 @@
+use chrono::NaiveDate;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::llm_usage::{LlmUsage, NewLlmUsage},
+};
+
+pub async fn upsert_llm_usage(pool: &MySqlPool, usage: &NewLlmUsage) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        INSERT INTO llm_usage (usage_date, provider, model, request_count, input_tokens, output_tokens, estimated_cost)
+        VALUES (?, ?, ?, 1, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            request_count = request_count + 1,
+            input_tokens = input_tokens + VALUES(input_tokens),
+            output_tokens = output_tokens + VALUES(output_tokens),
+            estimated_cost = estimated_cost + VALUES(estimated_cost)
+        "#,
+        usage.usage_date,
+        usage.provider,
+        usage.model,
+        usage.input_tokens,
+        usage.output_tokens,
+        usage.estimated_cost,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_llm_usages_since(
+    pool: &MySqlPool,
+    since: NaiveDate,
+) -> Result<Vec<LlmUsage>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        LlmUsage,
+        r#"
+        SELECT usage_date, provider, model, request_count, input_tokens, output_tokens, estimated_cost
+        FROM llm_usage
+        WHERE usage_date >= ?
+        ORDER BY usage_date DESC, provider, model
+        "#,
+        since,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
@@ -1,5 +1,11 @@
 pub mod embedding_repository;
+pub mod llm_usage_repository;
+pub mod news_cluster_repository;
 pub mod news_repository;
+pub mod recommendation_repository;
+pub mod retention_repository;
 pub mod rss_channel_repository;
 pub mod rss_item_repository;
+pub mod search_repository;
+pub mod summary_cache_repository;
 pub mod user_repository;
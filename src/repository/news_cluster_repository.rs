@@ -0,0 +1,132 @@
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{db_util::get_db, model::news::RecentNewsEmbedding};
+
+pub async fn insert_news_cluster(
+    pool: &MySqlPool,
+    representative_news_id: i32,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        INSERT INTO news_cluster (representative_news_id, news_cluster_size)
+        VALUES (?, 1)
+        "#,
+        representative_news_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn increase_news_cluster_size(
+    pool: &MySqlPool,
+    news_cluster_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        UPDATE news_cluster SET news_cluster_size = news_cluster_size + 1
+        WHERE news_cluster_id = ?
+        "#,
+        news_cluster_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_recent_news_embeddings(
+    pool: &MySqlPool,
+    window_hours: i64,
+    embedding_model_version: i32,
+) -> Result<Vec<RecentNewsEmbedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RecentNewsEmbedding,
+        r#"
+        SELECT n.news_id, n.news_cluster_id, n.news_summary, n.news_keywords,
+            n.news_entities, n.news_sentiment, n.news_analysis_category,
+            n.news_cluster_embedding AS embedding_value
+        FROM news n
+        WHERE n.news_pub_date >= DATE_SUB(NOW(), INTERVAL ? HOUR)
+            AND n.news_cluster_embedding_version = ?
+        "#,
+        window_hours,
+        embedding_model_version,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 대표 뉴스가 삭제된 클러스터는 남은 뉴스 중 가장 먼저 저장된 뉴스를 대표로 바꾸고,
+/// 클러스터 크기를 실제 소속 뉴스 수로 맞춤.
+pub async fn repair_news_clusters(pool: &MySqlPool) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        UPDATE news_cluster c
+        JOIN (
+            SELECT news_cluster_id, MIN(news_id) AS first_news_id, COUNT(*) AS size
+            FROM news
+            WHERE news_cluster_id IS NOT NULL
+            GROUP BY news_cluster_id
+        ) n ON n.news_cluster_id = c.news_cluster_id
+        SET c.representative_news_id = IF(
+                EXISTS (SELECT 1 FROM news WHERE news.news_id = c.representative_news_id),
+                c.representative_news_id,
+                n.first_news_id
+            ),
+            c.news_cluster_size = n.size
+        WHERE c.news_cluster_size <> n.size
+            OR NOT EXISTS (SELECT 1 FROM news WHERE news.news_id = c.representative_news_id)
+        "#
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 소속 뉴스가 모두 삭제된 클러스터를 삭제함.
+/// 생성 직후 대표 뉴스에 클러스터 ID가 기록되기 전인 클러스터는 건너뜀.
+pub async fn delete_empty_news_clusters(pool: &MySqlPool) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE FROM news_cluster
+        WHERE created_at < DATE_SUB(NOW(), INTERVAL 1 HOUR)
+            AND NOT EXISTS (
+                SELECT 1 FROM news WHERE news.news_cluster_id = news_cluster.news_cluster_id
+            )
+        "#
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
@@ -1,10 +1,13 @@
-use sqlx::{query, MySqlPool};
+use sqlx::{query, query_as, MySqlPool};
 
-use crate::{db_util::get_db, model::news::NewNews};
+use crate::{
+    db_util::get_db,
+    model::news::{NewNews, NewsEmbeddingSource},
+};
 
 pub async fn select_news_by_title(
     pool: &MySqlPool,
-    news_title: String,
+    news_title: &str,
 ) -> Result<Option<i32>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
@@ -28,8 +31,8 @@ pub async fn insert_news(pool: &MySqlPool, news: NewNews) -> Result<i32, sqlx::E
 
     let result = query!(
         r#"
-        INSERT INTO news (news_title, news_description, news_summary, news_link, news_source, news_pub_date, news_image_link, news_category)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO news (news_title, news_description, news_summary, news_link, news_source, news_pub_date, news_image_link, news_category, news_cluster_id, news_keywords, news_entities, news_sentiment, news_analysis_category, news_cluster_embedding, news_cluster_embedding_version)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
         news.news_title,
         news.news_description,
@@ -39,6 +42,13 @@ pub async fn insert_news(pool: &MySqlPool, news: NewNews) -> Result<i32, sqlx::E
         news.news_pub_date,
         news.news_image_link,
         news.news_category,
+        news.news_cluster_id,
+        news.news_keywords,
+        news.news_entities,
+        news.news_sentiment,
+        news.news_analysis_category,
+        news.news_cluster_embedding,
+        news.news_cluster_embedding_version,
     ).execute(&mut *conn).await;
 
     match result {
@@ -47,19 +57,58 @@ pub async fn insert_news(pool: &MySqlPool, news: NewNews) -> Result<i32, sqlx::E
     }
 }
 
-pub async fn delete_old_news(pool: &MySqlPool) -> Result<i32, sqlx::Error> {
+pub async fn update_news_cluster_id(
+    pool: &MySqlPool,
+    news_id: i32,
+    news_cluster_id: i32,
+) -> Result<bool, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
         r#"
-        DELETE FROM news WHERE news_pub_date < DATE_SUB(CURDATE(), INTERVAL 7 DAY)
-        "#
+        UPDATE news SET news_cluster_id = ? WHERE news_id = ?
+        "#,
+        news_cluster_id,
+        news_id,
     )
     .execute(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.rows_affected() as i32),
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 주어진 모델 버전의 임베딩이 없는 뉴스를 news_id 순으로 가져옴. `after_news_id` 이후부터 조회함.
+pub async fn select_news_without_embedding(
+    pool: &MySqlPool,
+    embedding_model_version: i32,
+    after_news_id: i32,
+    limit: i64,
+) -> Result<Vec<NewsEmbeddingSource>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        NewsEmbeddingSource,
+        r#"
+        SELECT n.news_id, n.news_title, n.news_description, n.news_summary
+        FROM news n
+        LEFT JOIN embedding e
+            ON e.news_id = n.news_id AND e.embedding_model_version = ?
+        WHERE e.embedding_id IS NULL AND n.news_id > ?
+        ORDER BY n.news_id
+        LIMIT ?
+        "#,
+        embedding_model_version,
+        after_news_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
         Err(e) => Err(e),
     }
 }
@@ -0,0 +1,144 @@
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::recommendation::{
+        NewRecommendation, ReadEmbedding, RecommendationUser, SubscribedChannelEmbedding,
+    },
+};
+
+/// 구독 채널이 있거나 최근 `read_days`일 안에 읽은 항목이 있는 사용자.
+pub async fn select_recommendation_users(
+    pool: &MySqlPool,
+    read_days: i64,
+) -> Result<Vec<RecommendationUser>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        SELECT u.user_id FROM user u
+        WHERE EXISTS (
+                SELECT 1 FROM user_subscription_channel usc WHERE usc.user_id = u.user_id
+            )
+            OR EXISTS (
+                SELECT 1 FROM user_read_history h
+                WHERE h.user_id = u.user_id AND h.read_at >= DATE_SUB(NOW(), INTERVAL ? DAY)
+            )
+        ORDER BY u.user_id
+        "#,
+        read_days,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res
+            .into_iter()
+            .map(|r| RecommendationUser { user_id: r.user_id })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_subscribed_channel_embeddings(
+    pool: &MySqlPool,
+    user_id: i32,
+    embedding_model_version: i32,
+) -> Result<Vec<SubscribedChannelEmbedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SubscribedChannelEmbedding,
+        r#"
+        SELECT usc.channel_id, e.embedding_value
+        FROM user_subscription_channel usc
+        LEFT JOIN embedding e ON e.channel_id = usc.channel_id
+            AND e.rss_id IS NULL AND e.news_id IS NULL
+            AND e.embedding_model_version = ?
+        WHERE usc.user_id = ?
+        "#,
+        embedding_model_version,
+        user_id,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 최근 `read_days`일 안에 읽은 RSS 아이템과 뉴스의 임베딩.
+pub async fn select_read_embeddings(
+    pool: &MySqlPool,
+    user_id: i32,
+    embedding_model_version: i32,
+    read_days: i64,
+) -> Result<Vec<ReadEmbedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        ReadEmbedding,
+        r#"
+        SELECT h.rss_id, h.news_id, e.embedding_value
+        FROM user_read_history h
+        JOIN embedding e ON e.rss_id = h.rss_id
+            AND e.channel_id IS NULL AND e.news_id IS NULL
+            AND e.embedding_model_version = ?
+        WHERE h.user_id = ? AND h.read_at >= DATE_SUB(NOW(), INTERVAL ? DAY)
+        UNION ALL
+        SELECT h.rss_id, h.news_id, e.embedding_value
+        FROM user_read_history h
+        JOIN embedding e ON e.news_id = h.news_id
+            AND e.channel_id IS NULL AND e.rss_id IS NULL
+            AND e.embedding_model_version = ?
+        WHERE h.user_id = ? AND h.read_at >= DATE_SUB(NOW(), INTERVAL ? DAY)
+        "#,
+        embedding_model_version,
+        user_id,
+        read_days,
+        embedding_model_version,
+        user_id,
+        read_days,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 사용자의 추천 목록을 새 목록으로 교체함. 읽는 쪽이 빈 목록을 보지 않도록 한 트랜잭션에서 처리함.
+pub async fn replace_user_recommendations(
+    pool: &MySqlPool,
+    user_id: i32,
+    recommendations: &[NewRecommendation],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    query!("DELETE FROM user_recommendation WHERE user_id = ?", user_id,)
+        .execute(&mut *tx)
+        .await?;
+
+    for recommendation in recommendations {
+        query!(
+            r#"
+            INSERT INTO user_recommendation
+                (user_id, recommendation_rank, rss_id, news_id, recommendation_score)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+            user_id,
+            recommendation.recommendation_rank,
+            recommendation.rss_id,
+            recommendation.news_id,
+            recommendation.recommendation_score,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
+}
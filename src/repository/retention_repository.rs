@@ -0,0 +1,282 @@
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::retention::{ExpiredRow, RetentionFilter},
+};
+
+pub async fn count_expired_news(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        SELECT COUNT(*) AS count FROM news
+        WHERE news_pub_date < ?
+            AND (? IS NULL OR news_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(news_category, '')))
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.count),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_expired_news(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+    limit: i64,
+) -> Result<Vec<ExpiredRow>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        ExpiredRow,
+        r#"
+        SELECT news_id AS id,
+            CAST(JSON_OBJECT(
+                'news_id', news_id,
+                'news_title', news_title,
+                'news_description', news_description,
+                'news_summary', news_summary,
+                'news_link', news_link,
+                'news_source', news_source,
+                'news_pub_date', news_pub_date,
+                'news_image_link', news_image_link,
+                'news_category', news_category,
+                'news_cluster_id', news_cluster_id,
+                'news_keywords', news_keywords,
+                'news_entities', news_entities,
+                'news_sentiment', news_sentiment,
+                'news_analysis_category', news_analysis_category
+            ) AS CHAR) AS row_json
+        FROM news
+        WHERE news_pub_date < ?
+            AND (? IS NULL OR news_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(news_category, '')))
+        ORDER BY news_id
+        LIMIT ?
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `news_id`가 `max_news_id` 이하인 만료 뉴스의 임베딩을 삭제함.
+pub async fn delete_expired_news_embeddings(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+    max_news_id: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE embedding FROM embedding
+        JOIN news ON embedding.news_id = news.news_id
+        WHERE news.news_pub_date < ?
+            AND (? IS NULL OR news.news_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(news.news_category, '')))
+            AND news.news_id <= ?
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+        max_news_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_expired_news(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+    max_news_id: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE FROM news
+        WHERE news_pub_date < ?
+            AND (? IS NULL OR news_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(news_category, '')))
+            AND news_id <= ?
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+        max_news_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_expired_rss_items(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        SELECT COUNT(*) AS count FROM rss_item
+        WHERE rss_pub_date < ?
+            AND (? IS NULL OR rss_analysis_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(rss_analysis_category, '')))
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.count),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_expired_rss_items(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+    limit: i64,
+) -> Result<Vec<ExpiredRow>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        ExpiredRow,
+        r#"
+        SELECT rss_id AS id,
+            CAST(JSON_OBJECT(
+                'rss_id', rss_id,
+                'channel_id', channel_id,
+                'rss_title', rss_title,
+                'rss_description', rss_description,
+                'rss_link', rss_link,
+                'rss_author', rss_author,
+                'rss_pub_date', rss_pub_date,
+                'rss_rank', rss_rank,
+                'rss_image_link', rss_image_link,
+                'rss_summary', rss_summary,
+                'rss_keywords', rss_keywords,
+                'rss_entities', rss_entities,
+                'rss_sentiment', rss_sentiment,
+                'rss_analysis_category', rss_analysis_category
+            ) AS CHAR) AS row_json
+        FROM rss_item
+        WHERE rss_pub_date < ?
+            AND (? IS NULL OR rss_analysis_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(rss_analysis_category, '')))
+        ORDER BY rss_id
+        LIMIT ?
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `rss_id`가 `max_rss_id` 이하인 만료 RSS 아이템의 임베딩을 삭제함.
+pub async fn delete_expired_rss_item_embeddings(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+    max_rss_id: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE embedding FROM embedding
+        JOIN rss_item ON embedding.rss_id = rss_item.rss_id
+        WHERE rss_item.rss_pub_date < ?
+            AND (? IS NULL OR rss_item.rss_analysis_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(rss_item.rss_analysis_category, '')))
+            AND rss_item.rss_id <= ?
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+        max_rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_expired_rss_items(
+    pool: &MySqlPool,
+    filter: &RetentionFilter,
+    max_rss_id: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE FROM rss_item
+        WHERE rss_pub_date < ?
+            AND (? IS NULL OR rss_analysis_category = ?)
+            AND NOT JSON_CONTAINS(?, JSON_QUOTE(COALESCE(rss_analysis_category, '')))
+            AND rss_id <= ?
+        "#,
+        filter.cutoff,
+        filter.category,
+        filter.category,
+        filter.excluded_categories,
+        max_rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
@@ -1,6 +1,12 @@
-use sqlx::{query, MySqlPool};
+use sqlx::{query, query_as, MySqlPool};
 
-use crate::{db_util::get_db, model::rss::NewRssItem};
+use crate::{
+    db_util::get_db,
+    model::{
+        analysis::ArticleAnalysis,
+        rss::{NewRssItem, RssItemAnalysisSource},
+    },
+};
 
 pub async fn is_exist_rss_item_by_link(
     pool: &MySqlPool,
@@ -61,3 +67,81 @@ pub async fn select_rss_items_len_by_channel_id(
         Err(e) => Err(e),
     }
 }
+
+/// 분석되지 않았고 실패 횟수가 `max_attempts` 미만인 아이템을 조회함.
+pub async fn select_unanalyzed_rss_items(
+    pool: &MySqlPool,
+    max_attempts: i32,
+    limit: i64,
+) -> Result<Vec<RssItemAnalysisSource>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        RssItemAnalysisSource,
+        "SELECT rss_id, rss_title, rss_description FROM rss_item
+            WHERE rss_analyzed_at IS NULL AND rss_analysis_attempts < ?
+            ORDER BY rss_id DESC
+            LIMIT ?;",
+        max_attempts,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 분석 결과를 저장하고 분석 완료 시각을 기록함.
+pub async fn update_rss_item_analysis(
+    pool: &MySqlPool,
+    rss_id: i32,
+    analysis: &ArticleAnalysis,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let summary = analysis.summary.chars().take(200).collect::<String>();
+    let keywords = analysis.keywords_json();
+    let entities = analysis.entities_json();
+    let category = analysis.category();
+    let sentiment = category.as_ref().map(|_| analysis.sentiment.as_str());
+
+    let result = query!(
+        "UPDATE rss_item
+            SET rss_summary = ?, rss_keywords = ?, rss_entities = ?, rss_analysis_category = ?,
+                rss_sentiment = ?, rss_analyzed_at = NOW()
+            WHERE rss_id = ?;",
+        summary,
+        keywords,
+        entities,
+        category,
+        sentiment,
+        rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 분석 실패 횟수를 1 늘림. 아이템은 분석 대기 상태로 남음.
+pub async fn increase_rss_item_analysis_attempts(
+    pool: &MySqlPool,
+    rss_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "UPDATE rss_item SET rss_analysis_attempts = rss_analysis_attempts + 1 WHERE rss_id = ?;",
+        rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,121 @@
+use chrono::NaiveDateTime;
+use sqlx::{query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::search::{ChannelSearchResult, NewsSearchResult, RssItemSearchResult},
+};
+
+// 임베딩 ID 목록은 JSON 배열로 넘겨 JSON_TABLE로 펼침. 순서는 보장하지 않으므로 호출부에서 맞춤.
+fn embedding_ids_json(embedding_ids: &[i32]) -> String {
+    serde_json::to_string(embedding_ids).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// 인덱스 검색 결과의 임베딩 ID들에 해당하는 채널. 삭제되었거나 언어가 맞지 않는 채널은 빠짐.
+pub async fn select_channel_search_results(
+    pool: &MySqlPool,
+    embedding_ids: &[i32],
+    language: Option<&str>,
+) -> Result<Vec<ChannelSearchResult>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        ChannelSearchResult,
+        r#"
+        SELECT e.embedding_id, c.channel_id, c.channel_title, c.channel_link,
+            c.channel_description, c.channel_image_url, c.channel_language, c.channel_rss_link
+        FROM JSON_TABLE(?, '$[*]' COLUMNS (embedding_id INT PATH '$')) ids
+        JOIN embedding e ON e.embedding_id = ids.embedding_id
+        JOIN rss_channel c ON c.channel_id = e.channel_id
+        WHERE e.rss_id IS NULL AND e.news_id IS NULL
+            AND (? IS NULL OR LEFT(c.channel_language, CHAR_LENGTH(?)) = ?)
+        "#,
+        embedding_ids_json(embedding_ids),
+        language,
+        language,
+        language,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 인덱스 검색 결과의 임베딩 ID들에 해당하는 RSS 아이템. 언어는 아이템이 속한 채널의 언어로 비교함.
+pub async fn select_rss_search_results(
+    pool: &MySqlPool,
+    embedding_ids: &[i32],
+    language: Option<&str>,
+    since: Option<NaiveDateTime>,
+    until: Option<NaiveDateTime>,
+) -> Result<Vec<RssItemSearchResult>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        RssItemSearchResult,
+        r#"
+        SELECT e.embedding_id, r.rss_id, r.channel_id, c.channel_title, c.channel_language,
+            r.rss_title, r.rss_description, r.rss_link, r.rss_author, r.rss_pub_date,
+            r.rss_image_link
+        FROM JSON_TABLE(?, '$[*]' COLUMNS (embedding_id INT PATH '$')) ids
+        JOIN embedding e ON e.embedding_id = ids.embedding_id
+        JOIN rss_item r ON r.rss_id = e.rss_id
+        JOIN rss_channel c ON c.channel_id = r.channel_id
+        WHERE e.channel_id IS NULL AND e.news_id IS NULL
+            AND (? IS NULL OR LEFT(c.channel_language, CHAR_LENGTH(?)) = ?)
+            AND (? IS NULL OR r.rss_pub_date >= ?)
+            AND (? IS NULL OR r.rss_pub_date < ?)
+        "#,
+        embedding_ids_json(embedding_ids),
+        language,
+        language,
+        language,
+        since,
+        since,
+        until,
+        until,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 인덱스 검색 결과의 임베딩 ID들에 해당하는 뉴스.
+pub async fn select_news_search_results(
+    pool: &MySqlPool,
+    embedding_ids: &[i32],
+    since: Option<NaiveDateTime>,
+    until: Option<NaiveDateTime>,
+) -> Result<Vec<NewsSearchResult>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        NewsSearchResult,
+        r#"
+        SELECT e.embedding_id, n.news_id, n.news_title, n.news_description, n.news_summary,
+            n.news_link, n.news_source, n.news_pub_date, n.news_image_link, n.news_category
+        FROM JSON_TABLE(?, '$[*]' COLUMNS (embedding_id INT PATH '$')) ids
+        JOIN embedding e ON e.embedding_id = ids.embedding_id
+        JOIN news n ON n.news_id = e.news_id
+        WHERE e.channel_id IS NULL AND e.rss_id IS NULL
+            AND (? IS NULL OR n.news_pub_date >= ?)
+            AND (? IS NULL OR n.news_pub_date < ?)
+        "#,
+        embedding_ids_json(embedding_ids),
+        since,
+        since,
+        until,
+        until,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,80 @@
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::summary_cache::{NewSummaryCache, SummaryCache},
+};
+
+pub async fn select_summary_caches(
+    pool: &MySqlPool,
+    content_hash: &str,
+    prompt_version: i32,
+) -> Result<Vec<SummaryCache>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SummaryCache,
+        r#"
+        SELECT content_hash, prompt_version, model, summary, analysis
+        FROM summary_cache
+        WHERE content_hash = ? AND prompt_version = ?
+        "#,
+        content_hash,
+        prompt_version,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn upsert_summary_cache(
+    pool: &MySqlPool,
+    cache: &NewSummaryCache,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        INSERT INTO summary_cache (content_hash, prompt_version, model, summary, analysis)
+        VALUES (?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE summary = VALUES(summary), analysis = VALUES(analysis)
+        "#,
+        cache.content_hash,
+        cache.prompt_version,
+        cache.model,
+        cache.summary,
+        cache.analysis,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_summary_caches_except_version(
+    pool: &MySqlPool,
+    prompt_version: i32,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        r#"
+        DELETE FROM summary_cache WHERE prompt_version <> ?
+        "#,
+        prompt_version,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() as i32),
+        Err(e) => Err(e),
+    }
+}
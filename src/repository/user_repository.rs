@@ -28,3 +28,27 @@ pub async fn selsect_users_fcm_token_subscribed_channel_by_channel_id(
         Err(e) => Err(e),
     }
 }
+
+/// 푸시 알림을 켜 둔 사용자의 FCM 토큰. 토큰이 없거나 알림을 껐으면 `None`.
+pub async fn select_user_fcm_token_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<FcmTokenUser>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT u.user_email, u.user_fcm_token FROM user u
+        WHERE u.user_id = ? AND u.user_fcm_token IS NOT NULL AND u.user_notification_push = true",
+        user_id
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.map(|r| FcmTokenUser {
+            user_email: Some(r.user_email),
+            user_fcm_token: r.user_fcm_token,
+        })),
+        Err(e) => Err(e),
+    }
+}
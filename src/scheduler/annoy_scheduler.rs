@@ -6,8 +6,8 @@ use tokio::time::{interval_at, Instant};
 use crate::{annoy_error, annoy_info, utils::annoy_util::save_annoy};
 
 pub async fn save_annoy_scheduler(pool: &MySqlPool) {
-    // 1 hour
-    let mut interval = interval_at(Instant::now(), Duration::from_secs(3600));
+    // 10 minutes. 바뀐 임베딩이 충분한 인덱스만 다시 만듦
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(600));
 
     loop {
         interval.tick().await;
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use sqlx::MySqlPool;
+use tokio::time::{interval_at, Instant};
+
+use crate::{
+    annoy_error, service::embedding_migration_service, utils::embedding_util::EmbeddingService,
+};
+
+pub async fn embedding_migration_scheduler(pool: &MySqlPool, embedding_service: &EmbeddingService) {
+    // 10분마다 모델 버전을 확인함. 모델 로딩이 끝나기 전에는 건너뜀
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(600));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) =
+            embedding_migration_service::migrate_embeddings(pool, embedding_service).await
+        {
+            annoy_error!("[Scheduler] Failed to migrate embeddings: {:?}", e);
+        }
+    }
+}
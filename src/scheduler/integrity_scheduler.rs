@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use sqlx::MySqlPool;
+use tokio::time::{interval_at, Instant};
+
+use crate::{config::env::get_env_or, news_error, service::integrity_service};
+
+pub async fn integrity_scheduler(pool: &MySqlPool) {
+    // 기본 1시간, Annoy 인덱스 저장 주기와 같음
+    let minutes = get_env_or("INTEGRITY_CHECK_INTERVAL_MINUTES", 60_u64).max(1);
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(60 * minutes));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = integrity_service::remove_orphans(pool).await {
+            news_error!("[Scheduler] Failed to check integrity: {:?}", e);
+        }
+    }
+}
@@ -1,5 +1,10 @@
 pub mod annoy_scheduler;
+pub mod embedding_migration_scheduler;
+pub mod integrity_scheduler;
 pub mod news_scheduler;
+pub mod recommendation_scheduler;
+pub mod retention_scheduler;
+pub mod rss_analysis_scheduler;
 pub mod rss_info_update_scheduler;
 pub mod rss_notification_scheduler;
 pub mod site;
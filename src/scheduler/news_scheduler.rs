@@ -1,16 +1,33 @@
 use std::time::Duration;
 
-use chrono::{Datelike, Utc};
 use sqlx::MySqlPool;
 use tokio::{
     task,
     time::{interval_at, Instant},
 };
 
-use crate::{global::FETCH_FLAG, news_error, news_info, news_warn};
+use crate::{
+    global::FETCH_FLAG,
+    news_error, news_info, news_warn,
+    service::{news_source::NewsSourceRegistry, summary_cache_service},
+    utils::{embedding_util::EmbeddingService, summarizer::SummarizerChain},
+};
+
+pub async fn fetch_news_scheduler(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    summarizer: &SummarizerChain,
+) {
+    // 프롬프트 버전이 바뀌었다면 이전 버전의 요약 캐시 정리
+    // 버전은 배포할 때만 바뀌므로 시작 시 한 번만 실행함
+    match summary_cache_service::purge_stale_summary_caches(pool).await {
+        Ok(count) => news_info!("[Scheduler] Purged {} stale summary caches", count),
+        Err(e) => news_error!("[Scheduler] Failed to purge stale summary caches: {}", e),
+    }
 
-pub async fn fetch_news_scheduler(pool: &MySqlPool) {
-    let mut interval = interval_at(Instant::now(), Duration::from_secs(300)); // 5 minutes
+    // 소스별 수집 주기는 news_sources.json에서 설정하며, 1분마다 주기가 돌아온 소스를 확인함
+    let registry = NewsSourceRegistry::from_config();
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(60)); // 1 minute
 
     loop {
         interval.tick().await;
@@ -30,7 +47,14 @@ pub async fn fetch_news_scheduler(pool: &MySqlPool) {
         .await
         .unwrap();
 
-        match crate::service::news_service::crawl_news_and_store_every_5_minutes(pool).await {
+        match crate::service::news_service::crawl_due_news_and_store(
+            pool,
+            embedding_service,
+            summarizer,
+            &registry,
+        )
+        .await
+        {
             Ok(_) => {
                 news_info!("[Scheduler] Successfully fetched news");
             }
@@ -49,30 +73,20 @@ pub async fn fetch_news_scheduler(pool: &MySqlPool) {
     }
 }
 
-pub async fn delete_old_news_scheduler(pool: &MySqlPool) {
-    let now = Utc::now();
-    let midnight = now.date_naive().and_hms_opt(0, 0, 0).unwrap();
-    let next_midnight = if now.time() < midnight.time() {
-        midnight
-    } else {
-        midnight + chrono::Duration::days(1)
-    };
+pub async fn backfill_news_embedding_scheduler(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+) {
+    // 1 hour, Annoy 인덱스 저장 전에 누락된 뉴스 임베딩을 채워둠
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(3600));
 
-    let wait_time = (next_midnight - now.naive_utc()).to_std().unwrap();
-
-    tokio::time::sleep(wait_time).await;
-
-    let mut interval = interval_at(Instant::now(), Duration::from_secs(86400)); // 24
-                                                                                // hours
     loop {
         interval.tick().await;
 
-        let today = Utc::now().weekday();
-        if today == chrono::Weekday::Sun {
-            match crate::service::news_service::delete_old_news(pool).await {
-                Ok(_) => news_info!("[Scheduler] Successfully deleted old news"),
-                Err(e) => news_error!("[Scheduler] Failed to delete old news: {:?}", e),
-            }
+        match crate::service::news_service::backfill_news_embeddings(pool, embedding_service).await
+        {
+            Ok(count) => news_info!("[Scheduler] Backfilled {} news embeddings", count),
+            Err(e) => news_error!("[Scheduler] Failed to backfill news embeddings: {:?}", e),
         }
     }
 }
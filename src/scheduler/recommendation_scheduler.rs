@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use chrono::{FixedOffset, Utc};
+use sqlx::MySqlPool;
+use tokio::time::{interval_at, Instant};
+
+use crate::{
+    config::env::get_env_or, recommendation_error, recommendation_info,
+    service::recommendation_service, utils::index_searcher::IndexSearcher,
+};
+
+pub async fn recommendation_scheduler(pool: &MySqlPool, searcher: &IndexSearcher) {
+    // 다이제스트 알림이 아침에 가도록 KST `RECOMMENDATION_HOUR`시(기본 8시)에 실행
+    let hour = get_env_or("RECOMMENDATION_HOUR", 8_u32).min(23);
+    let now = Utc::now()
+        .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+        .naive_local();
+    let mut next_run = now.date().and_hms_opt(hour, 0, 0).unwrap();
+    if next_run <= now {
+        next_run += chrono::Duration::days(1);
+    }
+
+    let wait_time = (next_run - now).to_std().unwrap_or_default();
+
+    tokio::time::sleep(wait_time).await;
+
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(86400)); // 24 hours
+
+    loop {
+        interval.tick().await;
+
+        recommendation_info!("[Scheduler] Recommendation Scheduler started");
+        if let Err(e) = recommendation_service::create_recommendations(pool, searcher).await {
+            recommendation_error!("[Scheduler] Failed to create recommendations: {:?}", e);
+        }
+    }
+}
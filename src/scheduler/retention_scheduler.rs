@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::MySqlPool;
+use tokio::time::{interval_at, Instant};
+
+use crate::{news_error, news_info, service::retention_service};
+
+pub async fn retention_scheduler(pool: &MySqlPool) {
+    let now = Utc::now();
+    let next_midnight = now.date_naive().and_hms_opt(0, 0, 0).unwrap() + chrono::Duration::days(1);
+
+    let wait_time = (next_midnight - now.naive_utc()).to_std().unwrap();
+
+    tokio::time::sleep(wait_time).await;
+
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(86400)); // 24 hours
+
+    loop {
+        interval.tick().await;
+
+        match retention_service::apply_retention_policies(pool).await {
+            Ok(reports) => news_info!("[Scheduler] Applied {} retention policies", reports.len()),
+            Err(e) => news_error!("[Scheduler] Failed to apply retention policies: {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use sqlx::MySqlPool;
+use tokio::time::{interval_at, Instant};
+
+use crate::{
+    config::env::get_env_or, rss_fetch_and_notification_error, rss_fetch_and_notification_info,
+    service::rss::item_service, utils::summarizer::SummarizerChain,
+};
+
+pub async fn rss_analysis_scheduler(pool: &MySqlPool, summarizer: &SummarizerChain) {
+    if !get_env_or("RSS_ITEM_ANALYSIS_ENABLED", true) {
+        rss_fetch_and_notification_info!("[Scheduler] Rss item analysis disabled");
+        return;
+    }
+
+    // 10 minutes, RSS 아이템 패치와 같은 주기
+    let mut interval = interval_at(Instant::now(), Duration::from_secs(60 * 10));
+
+    loop {
+        interval.tick().await;
+
+        match item_service::analyze_pending_rss_items(pool, summarizer).await {
+            Ok(_) => (),
+            Err(e) => {
+                rss_fetch_and_notification_error!("[Scheduler] Failed to analyze rss items: {}", e)
+            }
+        }
+    }
+}
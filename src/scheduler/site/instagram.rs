@@ -259,75 +259,113 @@ async fn build_item_not_exist_in_db(
     let mut items = Vec::new();
 
     let data_s = el.text().await.map_err(map_wd_err)?;
-    let data_v: Value = match serde_json::from_str(data_s.as_str()) {
+    let feeds = parse_user_timeline(&data_s)?;
+    sleep(Duration::from_millis(1000)).await;
+    for feed in feeds {
+        // 이미 link가 db에 존재하는지 확인.
+        if let Ok(res) = item_service::is_exist_rss_item_by_link(pool, &feed.link).await {
+            if res {
+                continue;
+            }
+        }
+
+        let item = ItemBuilder::default()
+            .title(feed.title)
+            .description(feed.description)
+            .link(feed.link)
+            .author(feed.author)
+            .pub_date(feed.pub_date.to_rfc2822())
+            .build();
+
+        let new_item = NewRssItem::new(
+            channel_id,
+            &item,
+            Some(feed.pub_date.naive_local()),
+            feed.image_link,
+        );
+
+        items.push(new_item);
+    }
+
+    Ok(items)
+}
+
+/// GraphQL 응답의 피드 하나.
+#[derive(Debug, Clone)]
+pub struct InstagramFeed {
+    pub title: String,
+    pub description: String,
+    pub link: String,
+    pub author: String,
+    pub pub_date: DateTime<FixedOffset>,
+    pub image_link: String,
+}
+
+/// user timeline GraphQL 응답을 피드 목록으로 변환함. 캡션이나 작성 시각이 없는 피드는 건너뜀.
+pub fn parse_user_timeline(data: &str) -> Result<Vec<InstagramFeed>, OmniNewsError> {
+    let data_v: Value = match serde_json::from_str(data) {
         Ok(v) => v,
         Err(e) => {
             error!("[Instagram-fetch] Failed to parse graphql data to json: {e}");
             return Err(OmniNewsError::ParseError);
         }
     };
-    let items_json = data_v
+    let Some(items_json) = data_v
         .get("data")
         .and_then(|v| v.get("xdt_api__v1__feed__user_timeline_graphql_connection"))
         .and_then(|v| v.get("edges"))
         .and_then(|v| v.as_array())
-        .unwrap();
-    sleep(Duration::from_millis(1000)).await;
+    else {
+        error!("[Instagram-fetch] Timeline edges not found in graphql data");
+        return Err(OmniNewsError::ParseError);
+    };
+
+    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    let mut feeds = Vec::new();
     for v in items_json {
-        let raw_texts = match v
-            .get("node")
-            .and_then(|v| v.get("caption"))
+        let Some(node) = v.get("node") else {
+            continue;
+        };
+        let Some(raw_texts) = node
+            .get("caption")
             .and_then(|v| v.get("text"))
-        {
-            Some(res) => res,
-            None => continue,
-        }
-        .to_string();
+            .and_then(|v| v.as_str())
+        else {
+            continue;
+        };
 
-        let texts = raw_texts.split("\\n").collect::<Vec<&str>>();
+        let texts = raw_texts.split('\n').collect::<Vec<&str>>();
 
-        let title = texts.first().unwrap_or(&"");
+        let title = texts.first().unwrap_or(&"").to_string();
         let description = texts.join(" ");
-        let feed_code = v
-            .get("node")
-            .and_then(|v| v.get("code"))
-            .and_then(|v| v.as_str());
 
-        let link = if let Some(code) = feed_code {
-            format!("http://instagram.com/p/{code}")
-        } else {
-            "".to_string()
-        };
+        let link = node
+            .get("code")
+            .and_then(|v| v.as_str())
+            .map(|code| format!("http://instagram.com/p/{code}"))
+            .unwrap_or_default();
 
-        let author = v
-            .get("node")
-            .and_then(|v| v.get("user"))
+        let author = node
+            .get("user")
             .and_then(|v| v.get("full_name"))
             .and_then(|v| v.as_str())
-            .unwrap_or("");
+            .unwrap_or("")
+            .to_string();
 
-        let pub_date_timestamp = match v
-            .get("node")
-            .and_then(|v| v.get("caption"))
+        let pub_date_timestamp = node
+            .get("caption")
             .and_then(|v| v.get("created_at"))
-        {
-            Some(v) => v.as_i64(),
-            None => v
-                .get("node")
-                .and_then(|v| v.get("taken_at"))
-                .and_then(|v| v.as_i64()),
-        }
-        .map(|v| Utc.timestamp_opt(v, 0))
-        .unwrap()
-        .unwrap();
-
-        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
-        let pub_date_kst: DateTime<FixedOffset> = pub_date_timestamp.with_timezone(&kst);
-        let pub_date_rfc2822 = pub_date_kst.to_rfc2822();
+            .and_then(|v| v.as_i64())
+            .or_else(|| node.get("taken_at").and_then(|v| v.as_i64()));
+        let Some(pub_date) = pub_date_timestamp
+            .and_then(|v| Utc.timestamp_opt(v, 0).single())
+            .map(|v| v.with_timezone(&kst))
+        else {
+            continue;
+        };
 
-        let image_link = v
-            .get("node")
-            .and_then(|v| v.get("image_versions2"))
+        let image_link = node
+            .get("image_versions2")
             .and_then(|v| v.get("candidates"))
             .and_then(|v| v.get(0))
             .and_then(|v| v.get("url"))
@@ -335,36 +373,17 @@ async fn build_item_not_exist_in_db(
             .unwrap_or("")
             .to_string();
 
-        // 이미 link가 db에 존재하는지 확인.
-        if let Ok(res) = item_service::is_exist_rss_item_by_link(pool, &link).await {
-            if res {
-                continue;
-            }
-        }
-
-        let item = ItemBuilder::default()
-            .title(title.to_string())
-            .description(description)
-            .link(link)
-            .author(author.to_string())
-            .pub_date(pub_date_rfc2822.clone())
-            .build();
-
-        info!("pub_date_rfc2822: {}", pub_date_rfc2822);
-        let pub_date = match DateTime::parse_from_rfc2822(&pub_date_rfc2822) {
-            Ok(res) => res.naive_local(),
-            Err(e) => {
-                error!("Failed to parse NaiveDateTime from pub_date_rfc2822: {e}");
-                return Err(OmniNewsError::ParseError);
-            }
-        };
-
-        let new_item = NewRssItem::new(channel_id, &item, Some(pub_date), image_link);
-
-        items.push(new_item);
+        feeds.push(InstagramFeed {
+            title,
+            description,
+            link,
+            author,
+            pub_date,
+            image_link,
+        });
     }
 
-    Ok(items)
+    Ok(feeds)
 }
 
 /* ---------------- Helper Functions ---------------- */
@@ -488,3 +507,43 @@ async fn get_meta_content(driver: &WebDriver, property: &str) -> Result<String,
 fn map_wd_err(e: WebDriverError) -> OmniNewsError {
     OmniNewsError::WebDriverError(e)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_user_timeline_fixture() {
+        let data = include_str!("../../../tests/fixtures/instagram_user_timeline.json");
+        let feeds = parse_user_timeline(data).unwrap();
+
+        // 캡션이 없는 피드는 제외됨
+        assert_eq!(feeds.len(), 2);
+
+        let first = &feeds[0];
+        assert_eq!(first.title, "가을 신메뉴 출시");
+        assert_eq!(
+            first.description,
+            "가을 신메뉴 출시 이번 주부터 전국 매장에서 만나보세요."
+        );
+        assert_eq!(first.link, "http://instagram.com/p/DAbCdEfGhIj");
+        assert_eq!(first.author, "옴니 카페");
+        // caption.created_at을 taken_at보다 우선 사용함
+        assert_eq!(first.pub_date.timestamp(), 1760500100);
+        assert_eq!(
+            first.image_link,
+            "https://scontent.cdninstagram.com/v/t51/1.jpg"
+        );
+
+        let second = &feeds[1];
+        assert_eq!(second.title, "영업시간 안내");
+        assert_eq!(second.pub_date.timestamp(), 1760300000);
+        assert!(second.image_link.is_empty());
+    }
+
+    #[test]
+    fn rejects_response_without_timeline() {
+        assert!(parse_user_timeline(r#"{"data": {}}"#).is_err());
+        assert!(parse_user_timeline("<html>login</html>").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use sqlx::MySqlPool;
+
+use crate::{
+    model::{
+        analysis::ArticleAnalysis,
+        error::{LlmError, OmniNewsError},
+        llm_usage::BudgetLevel,
+    },
+    news_error, news_info, news_warn,
+    service::{llm_usage_service, summary_cache_service},
+    utils::{
+        hash_util::normalized_content_hash,
+        summarizer::{ModelTier, Summarizer, SummarizerChain, SummaryRequest},
+    },
+};
+
+// 요약 목표 글자 수 (±10자)
+const SUMMARY_TARGET_LENGTH: usize = 50;
+
+/// 본문을 요약, 키워드, 카테고리, 개체명, 감성으로 분석함.
+/// 캐시 -> 예산 확인 -> LLM 호출 순으로 처리하며, 예산이 소진되면 `None`을 반환함.
+pub async fn analyze_content(
+    pool: &MySqlPool,
+    summarizer: &SummarizerChain,
+    content: String,
+) -> Result<Option<ArticleAnalysis>, OmniNewsError> {
+    // 같은 본문은 프롬프트 버전과 모델이 같으면 캐시된 분석을 사용함 (예산 소진 시에도 사용).
+    let content_hash = normalized_content_hash(&content);
+    let cached_models = [ModelTier::Default, ModelTier::Economy]
+        .into_iter()
+        .flat_map(|tier| summarizer.models(tier))
+        .collect::<Vec<_>>();
+    if let Some(analysis) =
+        summary_cache_service::find_cached_analysis(pool, &content_hash, &cached_models).await
+    {
+        news_info!("[Service] Summary cache hit: {}", content_hash);
+        return Ok(Some(analysis));
+    }
+
+    // 예산 소진 시 저렴한 모델 -> 분석 생략 순으로 단계적으로 낮춤.
+    // 사용량을 읽지 못하면 남은 예산을 알 수 없으므로 저렴한 모델을 사용함.
+    let budget_level = match llm_usage_service::get_budget_level(pool).await {
+        Ok(level) => level,
+        Err(e) => {
+            news_error!(
+                "[Service] Failed to get LLM budget level, use economy model: {}",
+                e
+            );
+            BudgetLevel::Economy
+        }
+    };
+    let model_tier = match budget_level {
+        BudgetLevel::Normal => ModelTier::Default,
+        BudgetLevel::Economy => ModelTier::Economy,
+        BudgetLevel::Exhausted => {
+            news_warn!("[Service] LLM budget exhausted, skip analysis");
+            return Ok(None);
+        }
+    };
+
+    let summary = match summarizer
+        .summarize(&SummaryRequest {
+            content,
+            target_length: SUMMARY_TARGET_LENGTH,
+            model_tier,
+        })
+        .await
+    {
+        Ok(summary) => summary,
+        Err(LlmError::AllProvidersFailed(discarded)) => {
+            // 모두 실패해도 버려진 응답의 비용은 원장에 남김
+            if let Err(e) = llm_usage_service::record_discarded_usage(pool, &discarded).await {
+                news_error!("[Service] Failed to record discarded llm usage: {}", e);
+            }
+            return Err(LlmError::AllProvidersFailed(discarded).into());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if let Err(e) = llm_usage_service::record_usage(pool, &summary).await {
+        news_error!("[Service] Failed to record llm usage: {}", e);
+    }
+    // 추출 요약은 비용이 없으므로 캐시하지 않음
+    if summary.provider != "extractive" {
+        if let Err(e) = summary_cache_service::save_analysis(pool, &content_hash, &summary).await {
+            news_warn!("[Service] Failed to save summary cache: {}", e);
+        }
+    }
+    news_info!(
+        "[Service] Analyzed content with {} ({})",
+        summary.provider,
+        summary.model
+    );
+
+    Ok(Some(summary.analysis))
+}
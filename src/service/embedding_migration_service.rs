@@ -0,0 +1,261 @@
+use sqlx::MySqlPool;
+
+use crate::{
+    annoy_error, annoy_info, annoy_warn,
+    config::env::get_env_or,
+    model::{embedding::NewEmbedding, error::OmniNewsError},
+    repository::{embedding_repository, news_repository},
+    service::{embedding_service, news_service, rss::channel_service::prepare_embedding_text},
+    utils::{annoy_util::save_annoy, embedding_util::EmbeddingService},
+};
+
+/// 현재 모델 버전(`EMBEDDING_MODEL_VERSION`)이 `active` 버전과 다르면 채널, RSS 아이템, 뉴스를
+/// 배치 단위로 다시 임베딩한 뒤 새 버전으로 전환함.
+/// 재임베딩이 끝나기 전까지 인덱스는 이전 버전으로 계속 만들어지고, 전환 직후 새 버전으로 다시 만든 뒤
+/// 이전 버전 임베딩을 삭제함. 중간에 중단되어도 다음 실행에서 남은 행부터 이어서 처리함.
+pub async fn migrate_embeddings(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+) -> Result<(), OmniNewsError> {
+    let Some(dimension) = embedding_service.dimension() else {
+        annoy_warn!("[Service] Embedding model is not ready, skip embedding migration.");
+        return Ok(());
+    };
+    let model_id = embedding_service.model_id();
+    let model_version = embedding_service.model_version();
+
+    embedding_repository::insert_embedding_model(pool, model_version, model_id, dimension as i32)
+        .await
+        .map_err(|e| {
+            annoy_error!("[Service] Failed to register embedding model: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    // 같은 버전 번호로 다른 모델을 쓰면 벡터 공간이 섞이므로 진행하지 않음
+    let registered = embedding_repository::select_embedding_model_by_version(pool, model_version)
+        .await
+        .map_err(OmniNewsError::Database)?;
+    if let Some(registered) = registered {
+        if registered.embedding_model_id.as_deref() != Some(model_id)
+            || registered.embedding_model_dimension != Some(dimension as i32)
+        {
+            annoy_error!(
+                "[Service] Embedding model version {} is registered as {:?} ({:?} dims), but {} ({} dims) is loaded. Increase EMBEDDING_MODEL_VERSION.",
+                model_version,
+                registered.embedding_model_id,
+                registered.embedding_model_dimension,
+                model_id,
+                dimension
+            );
+            return Ok(());
+        }
+    }
+
+    let active_version = embedding_repository::select_active_embedding_model(pool)
+        .await
+        .map_err(OmniNewsError::Database)?
+        .and_then(|model| model.embedding_model_version);
+    if active_version == Some(model_version) {
+        return Ok(());
+    }
+
+    annoy_info!(
+        "[Service] Migrating embeddings from version {:?} to {} ({})",
+        active_version,
+        model_version,
+        model_id
+    );
+    let batch_size = get_env_or("EMBEDDING_MIGRATION_BATCH_SIZE", 100_i64);
+
+    let channels = reembed_channels(pool, embedding_service, batch_size).await?;
+    let rss_items = reembed_rss_items(pool, embedding_service, batch_size).await?;
+    let newsses = news_service::backfill_news_embeddings(pool, embedding_service).await?;
+    annoy_info!(
+        "[Service] Re-embedded {} channels, {} rss items, {} news with version {}",
+        channels,
+        rss_items,
+        newsses,
+        model_version
+    );
+
+    // 실패한 행이 남아 있으면 전환하지 않고 다음 실행에서 다시 시도함
+    if has_remaining(pool, model_version).await? {
+        annoy_warn!(
+            "[Service] Some rows are not re-embedded with version {} yet, keep version {:?} active",
+            model_version,
+            active_version
+        );
+        return Ok(());
+    }
+
+    embedding_repository::activate_embedding_model(pool, model_version)
+        .await
+        .map_err(|e| {
+            annoy_error!("[Service] Failed to activate embedding model: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+    // 다음 저장 주기를 기다리지 않고 바로 새 버전 인덱스로 교체함
+    save_annoy(pool).await?;
+
+    let deleted = embedding_repository::delete_embeddings_except_version(pool, model_version)
+        .await
+        .map_err(|e| {
+            annoy_error!("[Service] Failed to delete old embeddings: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+    annoy_info!(
+        "[Service] Activated embedding model version {}, deleted {} old embeddings",
+        model_version,
+        deleted
+    );
+
+    Ok(())
+}
+
+async fn has_remaining(pool: &MySqlPool, model_version: i32) -> Result<bool, OmniNewsError> {
+    let channels = embedding_repository::select_channels_to_reembed(pool, model_version, 0, 1)
+        .await
+        .map_err(OmniNewsError::Database)?;
+    let rss_items = embedding_repository::select_rss_items_to_reembed(pool, model_version, 0, 1)
+        .await
+        .map_err(OmniNewsError::Database)?;
+    let newsses = news_repository::select_news_without_embedding(pool, model_version, 0, 1)
+        .await
+        .map_err(OmniNewsError::Database)?;
+
+    Ok(!channels.is_empty() || !rss_items.is_empty() || !newsses.is_empty())
+}
+
+async fn reembed_channels(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    batch_size: i64,
+) -> Result<i32, OmniNewsError> {
+    let model_version = embedding_service.model_version();
+    let mut last_channel_id = 0;
+    let mut created = 0;
+
+    loop {
+        let channels = embedding_repository::select_channels_to_reembed(
+            pool,
+            model_version,
+            last_channel_id,
+            batch_size,
+        )
+        .await
+        .map_err(|e| {
+            annoy_error!("[Service] Failed to select channels to re-embed: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+        if channels.is_empty() {
+            break;
+        }
+
+        // 실패한 채널을 다시 조회하지 않도록 커서는 항상 전진시킴
+        last_channel_id = channels
+            .iter()
+            .filter_map(|channel| channel.channel_id)
+            .max()
+            .unwrap_or(last_channel_id);
+
+        let sources = channels
+            .into_iter()
+            .filter_map(|channel| {
+                let sentence = prepare_embedding_text(
+                    &channel.channel_title.unwrap_or_default(),
+                    &channel.channel_description.unwrap_or_default(),
+                );
+                let embedding = NewEmbedding {
+                    embedding_value: None,
+                    channel_id: Some(channel.channel_id?),
+                    rss_id: None,
+                    news_id: None,
+                    embedding_source_rank: Some(0),
+                    embedding_model_id: None,
+                    embedding_model_version: None,
+                    embedding_content_hash: None,
+                };
+                Some((sentence, embedding))
+            })
+            .collect::<Vec<_>>();
+
+        match embedding_service::create_embeddings(pool, embedding_service, sources).await {
+            Ok(count) => created += count,
+            Err(e) => annoy_warn!(
+                "[Service] Failed to re-embed channels up to {}: {}",
+                last_channel_id,
+                e
+            ),
+        }
+    }
+
+    Ok(created)
+}
+
+async fn reembed_rss_items(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    batch_size: i64,
+) -> Result<i32, OmniNewsError> {
+    let model_version = embedding_service.model_version();
+    let mut last_rss_id = 0;
+    let mut created = 0;
+
+    loop {
+        let rss_items = embedding_repository::select_rss_items_to_reembed(
+            pool,
+            model_version,
+            last_rss_id,
+            batch_size,
+        )
+        .await
+        .map_err(|e| {
+            annoy_error!("[Service] Failed to select rss items to re-embed: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+        if rss_items.is_empty() {
+            break;
+        }
+
+        last_rss_id = rss_items
+            .iter()
+            .filter_map(|rss_item| rss_item.rss_id)
+            .max()
+            .unwrap_or(last_rss_id);
+
+        let sources = rss_items
+            .into_iter()
+            .filter_map(|rss_item| {
+                // 아이템 저장 시와 같은 형식 (제목, 설명, 작성자)
+                let sentence = format!(
+                    "{}\n{}\n{}",
+                    rss_item.rss_title.unwrap_or_default(),
+                    rss_item.rss_description.unwrap_or_default(),
+                    rss_item.rss_author.unwrap_or_default()
+                );
+                let embedding = NewEmbedding {
+                    embedding_value: None,
+                    channel_id: None,
+                    rss_id: Some(rss_item.rss_id?),
+                    news_id: None,
+                    embedding_source_rank: Some(0),
+                    embedding_model_id: None,
+                    embedding_model_version: None,
+                    embedding_content_hash: None,
+                };
+                Some((sentence, embedding))
+            })
+            .collect::<Vec<_>>();
+
+        match embedding_service::create_embeddings(pool, embedding_service, sources).await {
+            Ok(count) => created += count,
+            Err(e) => annoy_warn!(
+                "[Service] Failed to re-embed rss items up to {}: {}",
+                last_rss_id,
+                e
+            ),
+        }
+    }
+
+    Ok(created)
+}
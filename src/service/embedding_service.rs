@@ -1,14 +1,21 @@
+use chrono::NaiveDateTime;
 use sqlx::MySqlPool;
 
 use crate::{
+    annoy_error,
     model::{
-        embedding::{Embedding, NewEmbedding},
+        embedding::{Embedding, EmbeddingIndexStats, NewEmbedding},
         error::OmniNewsError,
         rss::NewticleType,
     },
     repository::embedding_repository,
-    rss_fetch_and_notification_error,
-    utils::embedding_util::{embedding_sentence, encode_embedding, EmbeddingService},
+    rss_info_info,
+    utils::{
+        embedding_util::{
+            decode_embedding, embedding_sentence, encode_embedding, EmbeddingService,
+        },
+        hash_util::normalized_content_hash,
+    },
 };
 
 pub async fn create_embedding(
@@ -17,76 +24,234 @@ pub async fn create_embedding(
     sentence: String,
     mut embedding: NewEmbedding,
 ) -> Result<i32, OmniNewsError> {
-    let embedding_value = embedding_sentence(embedding_service, sentence).await?;
-    let encoded_embedding_value = encode_embedding(&embedding_value);
+    let content_hash = normalized_content_hash(&sentence);
+    let embedding_value =
+        match find_cached_embedding(pool, embedding_service, &content_hash).await? {
+            Some(value) => value,
+            None => embedding_sentence(embedding_service, sentence).await?,
+        };
+    embedding.embedding_content_hash = Some(content_hash);
+    store_embedding(pool, embedding_service, &embedding_value, embedding).await
+}
 
-    embedding.embedding_value = Some(encoded_embedding_value);
+/// 여러 문장을 한 번에 임베딩해 각각 저장하고 저장된 개수를 반환함.
+/// 같은 텍스트의 임베딩이 이미 있으면 재사용하고, 나머지만 모델에 보냄.
+/// 저장에 실패한 행은 건너뛰며, 임베딩 생성 자체가 실패하면 오류를 반환함.
+pub async fn create_embeddings(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    sources: Vec<(String, NewEmbedding)>,
+) -> Result<i32, OmniNewsError> {
+    let mut cached = Vec::new();
+    let mut sentences = Vec::new();
+    let mut embeddings = Vec::new();
+    for (sentence, mut embedding) in sources {
+        let content_hash = normalized_content_hash(&sentence);
+        let cached_value = find_cached_embedding(pool, embedding_service, &content_hash).await?;
+        embedding.embedding_content_hash = Some(content_hash);
+        match cached_value {
+            Some(value) => cached.push((value, embedding)),
+            None => {
+                sentences.push(sentence);
+                embeddings.push(embedding);
+            }
+        }
+    }
+    let embedding_values = embedding_service.embed_many(sentences).await?;
+
+    let mut created = 0;
+    for (embedding_value, embedding) in cached
+        .into_iter()
+        .chain(embedding_values.into_iter().zip(embeddings))
+    {
+        if store_embedding(pool, embedding_service, &embedding_value, embedding)
+            .await
+            .is_ok()
+        {
+            created += 1;
+        }
+    }
+    Ok(created)
+}
+
+/// 현재 모델 버전에서 같은 텍스트로 만든 임베딩이 있으면 벡터를 반환함.
+/// 차원이 맞지 않으면 `None`으로 보고 새로 임베딩함. 조회 오류는 호출한 쪽에서 로깅함.
+async fn find_cached_embedding(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    content_hash: &str,
+) -> Result<Option<Vec<f32>>, OmniNewsError> {
+    let value = match embedding_repository::select_embedding_value_by_content_hash(
+        pool,
+        content_hash,
+        embedding_service.model_version(),
+    )
+    .await
+    {
+        Ok(Some(value)) => decode_embedding(&value),
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(OmniNewsError::Database(e)),
+    };
+
+    match embedding_service.dimension() {
+        Some(dimension) if dimension != value.len() => Ok(None),
+        _ => Ok(Some(value)),
+    }
+}
+
+/// 이미 계산된 임베딩 벡터를 현재 모델 ID/버전과 함께 저장함.
+async fn store_embedding(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    embedding_value: &[f32],
+    mut embedding: NewEmbedding,
+) -> Result<i32, OmniNewsError> {
+    embedding.embedding_value = Some(encode_embedding(embedding_value));
+    embedding.embedding_model_id = Some(embedding_service.model_id().to_string());
+    embedding.embedding_model_version = Some(embedding_service.model_version());
+
+    embedding_repository::insert_embedding(pool, embedding)
+        .await
+        .map_err(OmniNewsError::Database)
+}
 
-    match embedding_repository::insert_embedding(pool, embedding).await {
-        Ok(res) => Ok(res),
+/// 인덱스 종류별 임베딩 현황과 이전 빌드(`last_embedding_id`, `last_updated_at`) 이후 바뀐 행 수를 조회함.
+pub async fn find_embedding_index_stats(
+    pool: &MySqlPool,
+    category: &NewticleType,
+    embedding_model_version: i32,
+    last_embedding_id: i32,
+    last_updated_at: Option<NaiveDateTime>,
+) -> Result<EmbeddingIndexStats, OmniNewsError> {
+    let result = embedding_repository::select_embedding_index_stats(
+        pool,
+        category.as_str(),
+        embedding_model_version,
+        last_embedding_id,
+        last_updated_at,
+    )
+    .await;
+
+    match result {
+        Ok(stats) => Ok(stats),
         Err(e) => {
-            rss_fetch_and_notification_error!("[Service] Failed to insert embedding: {}", e);
-            Err(OmniNewsError::Embedding)
+            annoy_error!(
+                "[Service] Failed to select {:?} embedding stats: {}",
+                category,
+                e
+            );
+            Err(OmniNewsError::Database(e))
         }
     }
 }
 
-pub async fn find_all_embeddings_by(
+/// 인덱스를 만들 임베딩을 `embedding_id`순으로 한 페이지씩 조회함.
+/// `changed_since`가 있으면 그 워터마크(최대 ID, 갱신 시각) 이후 추가/갱신된 임베딩만 조회함.
+pub async fn find_embedding_page(
     pool: &MySqlPool,
-    category: NewticleType,
+    category: &NewticleType,
+    embedding_model_version: i32,
+    after_id: i32,
+    max_id: i32,
+    changed_since: Option<(i32, Option<NaiveDateTime>)>,
+    limit: i64,
 ) -> Result<Vec<Embedding>, OmniNewsError> {
-    match category {
-        NewticleType::Channel => {
-            match embedding_repository::select_all_channel_embeddings(pool).await {
-                Ok(embeddings) => Ok(embeddings),
-                Err(e) => {
-                    rss_fetch_and_notification_error!(
-                        "[Service] Failed to select all channel embeddings: {}",
-                        e
-                    );
-                    Err(OmniNewsError::Database(e))
-                }
-            }
+    let (changed_after_id, changed_after) = changed_since.unwrap_or((0, None));
+    let result = embedding_repository::select_embedding_page(
+        pool,
+        category.as_str(),
+        embedding_model_version,
+        after_id,
+        max_id,
+        changed_after_id,
+        changed_after,
+        limit,
+    )
+    .await;
+
+    match result {
+        Ok(embeddings) => Ok(embeddings),
+        Err(e) => {
+            annoy_error!(
+                "[Service] Failed to select {:?} embeddings: {}",
+                category,
+                e
+            );
+            Err(OmniNewsError::Database(e))
         }
-        NewticleType::Rss => match embedding_repository::select_all_rss_embeddings(pool).await {
-            Ok(embeddings) => Ok(embeddings),
-            Err(e) => {
-                rss_fetch_and_notification_error!(
-                    "[Service] Failed to select all rss embeddings: {}",
-                    e
-                );
-                Err(OmniNewsError::Database(e))
-            }
-        },
-        NewticleType::News => match embedding_repository::select_all_news_embeddings(pool).await {
-            Ok(embeddings) => Ok(embeddings),
-            Err(e) => {
-                rss_fetch_and_notification_error!(
-                    "[Service] Failed to select all news embeddings: {}",
-                    e
-                );
-                Err(OmniNewsError::Database(e))
-            }
-        },
     }
 }
 
+/// 인덱스 종류별 `embedding_id`를 ID순으로 한 페이지씩 조회함.
+pub async fn find_embedding_ids(
+    pool: &MySqlPool,
+    category: &NewticleType,
+    embedding_model_version: i32,
+    after_id: i32,
+    limit: i64,
+) -> Result<Vec<i32>, OmniNewsError> {
+    let result = embedding_repository::select_embedding_ids(
+        pool,
+        category.as_str(),
+        embedding_model_version,
+        after_id,
+        limit,
+    )
+    .await;
+
+    match result {
+        Ok(ids) => Ok(ids),
+        Err(e) => {
+            annoy_error!(
+                "[Service] Failed to select {:?} embedding ids: {}",
+                category,
+                e
+            );
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 채널 임베딩을 갱신함. 제목/설명으로 만든 텍스트가 이전과 같으면 모델 호출과 DB 쓰기를 생략하고 0을 반환함.
 pub async fn update_embedding(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
     sentence: String,
     mut embedding: NewEmbedding,
 ) -> Result<i32, OmniNewsError> {
-    let embedding_value = embedding_sentence(embedding_service, sentence).await?;
+    let channel_id = embedding.channel_id.unwrap_or_default();
+    let content_hash = normalized_content_hash(&sentence);
+    match embedding_repository::select_channel_embedding_content_hash(
+        pool,
+        channel_id,
+        embedding_service.model_version(),
+    )
+    .await
+    {
+        Ok(Some(previous)) if previous == content_hash => {
+            rss_info_info!(
+                "[Service] Channel {} embedding text unchanged, skip update",
+                channel_id
+            );
+            return Ok(0);
+        }
+        Ok(_) => (),
+        Err(e) => return Err(OmniNewsError::Database(e)),
+    }
+
+    let embedding_value =
+        match find_cached_embedding(pool, embedding_service, &content_hash).await? {
+            Some(value) => value,
+            None => embedding_sentence(embedding_service, sentence).await?,
+        };
     let encoded_embedding_value = encode_embedding(&embedding_value);
 
     embedding.embedding_value = Some(encoded_embedding_value);
+    embedding.embedding_content_hash = Some(content_hash);
+    // 현재 모델 버전의 임베딩만 갱신함. 새 버전 임베딩은 재임베딩 작업에서 생성됨.
+    embedding.embedding_model_version = Some(embedding_service.model_version());
 
-    match embedding_repository::update_embedding(pool, embedding).await {
-        Ok(res) => Ok(res),
-        Err(e) => {
-            rss_fetch_and_notification_error!("[Service] Failed to update embedding: {}", e);
-            Err(OmniNewsError::Embedding)
-        }
-    }
+    embedding_repository::update_embedding(pool, embedding)
+        .await
+        .map_err(OmniNewsError::Database)
 }
@@ -0,0 +1,182 @@
+use std::{collections::HashSet, env, fs, str::FromStr};
+
+use rand::{rngs::StdRng, SeedableRng};
+use sqlx::MySqlPool;
+
+use crate::{
+    annoy_info, annoy_warn,
+    config::env::get_env_or,
+    model::{error::OmniNewsError, index_evaluation::IndexEvaluation, rss::NewticleType},
+    repository::embedding_repository,
+    service::embedding_service,
+    utils::{
+        embedding_util::decode_embedding,
+        vector_index::{
+            evaluation::{brute_force_top_k, evaluate, normalized_corpus, IndexCandidate},
+            hnsw_params,
+        },
+    },
+};
+
+/// `eval-index` 명령. `active` 모델의 임베딩 중 일부를 쿼리로 떼어 내고, 나머지로 인덱스 설정마다
+/// 인덱스를 만들어 브루트포스 코사인 top-k 대비 recall@k, 빌드 시간, 인덱스 크기, 검색 지연을 보고함.
+pub async fn evaluate_indexes(pool: &MySqlPool) -> Result<Vec<IndexEvaluation>, OmniNewsError> {
+    let active_model = embedding_repository::select_active_embedding_model(pool)
+        .await
+        .map_err(OmniNewsError::Database)?;
+    let Some(active_model) = active_model else {
+        annoy_warn!("[Eval] No active embedding model, skip index evaluation.");
+        return Ok(Vec::new());
+    };
+    let model_version = active_model.embedding_model_version.unwrap_or_default();
+    let dimension = active_model.embedding_model_dimension.unwrap_or_default() as usize;
+
+    let category = NewticleType::parse(&env::var("INDEX_EVAL_KIND").unwrap_or_default())
+        .unwrap_or(NewticleType::News);
+    let k = get_env_or("INDEX_EVAL_K", 10_usize).max(1);
+    let query_count = get_env_or("INDEX_EVAL_QUERIES", 100_usize).max(1);
+    let limit = get_env_or("INDEX_EVAL_LIMIT", 20_000_usize);
+
+    let vectors = load_vectors(pool, &category, model_version, dimension, limit).await?;
+    if vectors.len() <= query_count + k {
+        annoy_warn!(
+            "[Eval] Not enough {:?} embeddings to evaluate: {} (queries {}, k {})",
+            category,
+            vectors.len(),
+            query_count,
+            k
+        );
+        return Ok(Vec::new());
+    }
+
+    // 쿼리로 뽑은 벡터는 인덱스에서 빼서 자기 자신이 top-1이 되지 않도록 함
+    let mut rng = StdRng::seed_from_u64(get_env_or("INDEX_EVAL_SEED", 42_u64));
+    let picked = rand::seq::index::sample(&mut rng, vectors.len(), query_count)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let (queries, corpus): (Vec<_>, Vec<_>) = vectors
+        .into_iter()
+        .enumerate()
+        .partition(|(i, _)| picked.contains(i));
+    let queries = queries
+        .into_iter()
+        .map(|(_, (_, vector))| vector)
+        .collect::<Vec<_>>();
+    let corpus = corpus.into_iter().map(|(_, item)| item).collect::<Vec<_>>();
+    annoy_info!(
+        "[Eval] Evaluating {:?} indexes with {} items, {} queries, k {}",
+        category,
+        corpus.len(),
+        queries.len(),
+        k
+    );
+
+    let candidates = candidates();
+    let evaluations = tokio::task::spawn_blocking(move || {
+        let exact = queries
+            .iter()
+            .map(|query| brute_force_top_k(&corpus, query, k))
+            .collect::<Vec<_>>();
+
+        let mut evaluations = Vec::new();
+        for candidate in &candidates {
+            match evaluate(candidate, &corpus, &queries, &exact, k, &env::temp_dir()) {
+                Ok(rows) => evaluations.extend(rows),
+                Err(e) => annoy_warn!("[Eval] Failed to evaluate {:?}: {}", candidate, e),
+            }
+        }
+        evaluations
+    })
+    .await
+    .map_err(|e| OmniNewsError::Io(std::io::Error::other(e)))?;
+
+    for evaluation in &evaluations {
+        annoy_info!(
+            "[Eval] {:<5} {:<40} recall@{} {:.4}  build {} ms  size {} KB  query mean {:.1} us  p95 {:.1} us",
+            evaluation.index,
+            evaluation.params,
+            k,
+            evaluation.recall,
+            evaluation.build_ms,
+            evaluation.index_bytes / 1024,
+            evaluation.mean_query_us,
+            evaluation.p95_query_us
+        );
+    }
+    if let Some(output) = env::var("INDEX_EVAL_OUTPUT")
+        .ok()
+        .filter(|path| !path.trim().is_empty())
+    {
+        fs::write(
+            &output,
+            serde_json::to_string_pretty(&evaluations).unwrap_or_default(),
+        )?;
+        annoy_info!("[Eval] Wrote results to {}", output);
+    }
+
+    Ok(evaluations)
+}
+
+/// `active` 모델 차원과 맞는 벡터를 ID순으로 최대 `limit`개 읽어 정규화함.
+async fn load_vectors(
+    pool: &MySqlPool,
+    category: &NewticleType,
+    model_version: i32,
+    dimension: usize,
+    limit: usize,
+) -> Result<Vec<(i32, Vec<f32>)>, OmniNewsError> {
+    let batch_size = get_env_or("ANNOY_LOAD_BATCH_SIZE", 1000_i64);
+    let mut vectors = Vec::new();
+    let mut last_embedding_id = 0;
+
+    while vectors.len() < limit {
+        let embeddings = embedding_service::find_embedding_page(
+            pool,
+            category,
+            model_version,
+            last_embedding_id,
+            i32::MAX,
+            None,
+            batch_size,
+        )
+        .await?;
+        let Some(page_max_id) = embeddings.iter().filter_map(|e| e.embedding_id).max() else {
+            break;
+        };
+        last_embedding_id = page_max_id;
+
+        vectors.extend(embeddings.into_iter().filter_map(|embedding| {
+            let vector = decode_embedding(embedding.embedding_value.as_deref()?);
+            (vector.len() == dimension).then(|| (embedding.embedding_id?, vector))
+        }));
+    }
+    vectors.truncate(limit);
+
+    Ok(normalized_corpus(vectors))
+}
+
+/// `INDEX_EVAL_ANNOY_TREES`(기본 `10,40,100`)와 `INDEX_EVAL_HNSW_EF_SEARCH`(기본 `16,64,128`).
+/// 빈 값이면 해당 인덱스는 평가하지 않음. HNSW의 `m`, `ef_construction`은 `HNSW_*` 설정을 사용함.
+fn candidates() -> Vec<IndexCandidate> {
+    let mut candidates = env_list::<i32>("INDEX_EVAL_ANNOY_TREES", "10,40,100")
+        .into_iter()
+        .map(|n_trees| IndexCandidate::Annoy { n_trees })
+        .collect::<Vec<_>>();
+
+    let ef_search = env_list::<usize>("INDEX_EVAL_HNSW_EF_SEARCH", "16,64,128");
+    if !ef_search.is_empty() {
+        candidates.push(IndexCandidate::Hnsw {
+            params: hnsw_params(),
+            ef_search,
+        });
+    }
+    candidates
+}
+
+fn env_list<T: FromStr>(key: &str, default: &str) -> Vec<T> {
+    env::var(key)
+        .unwrap_or_else(|_| default.to_string())
+        .split(',')
+        .filter_map(|value| value.trim().parse::<T>().ok())
+        .collect()
+}
@@ -0,0 +1,40 @@
+use sqlx::MySqlPool;
+
+use crate::{
+    model::error::OmniNewsError,
+    news_error, news_info,
+    repository::{embedding_repository, news_cluster_repository},
+};
+
+/// 삭제된 뉴스/RSS 아이템을 가리키는 임베딩과 클러스터를 정리함.
+/// 외래 키가 없는 환경이나 직접 삭제된 행도 다음 Annoy 재생성 전에 정리되도록 주기적으로 실행함.
+pub async fn remove_orphans(pool: &MySqlPool) -> Result<(), OmniNewsError> {
+    let embeddings = embedding_repository::delete_orphan_embeddings(pool)
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to delete orphan embeddings: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let repaired = news_cluster_repository::repair_news_clusters(pool)
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to repair news clusters: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let clusters = news_cluster_repository::delete_empty_news_clusters(pool)
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to delete empty news clusters: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    news_info!(
+        "[Service] Integrity check: deleted {} orphan embeddings, repaired {} clusters, deleted {} empty clusters",
+        embeddings,
+        repaired,
+        clusters
+    );
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use chrono::{Datelike, Duration, FixedOffset, NaiveDate, Utc};
+use sqlx::MySqlPool;
+
+use crate::{
+    config::env::get_env_or,
+    model::{
+        error::OmniNewsError,
+        llm_usage::{
+            BudgetLevel, DiscardedUsage, LlmUsage, LlmUsageReport, NewLlmUsage, TokenUsage,
+        },
+    },
+    news_error, news_warn,
+    repository::llm_usage_repository,
+    utils::summarizer::{pricing::estimate_cost_krw, Summary},
+};
+
+struct Budget {
+    daily: f64,
+    monthly: f64,
+    economy_ratio: f64,
+}
+
+impl Budget {
+    // 0 이하는 제한 없음
+    fn from_env() -> Self {
+        Self {
+            daily: get_env_or("LLM_DAILY_BUDGET_KRW", 0.0),
+            monthly: get_env_or("LLM_MONTHLY_BUDGET_KRW", 0.0),
+            economy_ratio: get_env_or("LLM_BUDGET_ECONOMY_RATIO", 0.8),
+        }
+    }
+
+    fn level(&self, today_cost: f64, month_cost: f64) -> BudgetLevel {
+        level_for(today_cost, self.daily, self.economy_ratio).max(level_for(
+            month_cost,
+            self.monthly,
+            self.economy_ratio,
+        ))
+    }
+}
+
+fn level_for(cost: f64, budget: f64, economy_ratio: f64) -> BudgetLevel {
+    if budget <= 0.0 {
+        BudgetLevel::Normal
+    } else if cost >= budget {
+        BudgetLevel::Exhausted
+    } else if cost >= budget * economy_ratio {
+        BudgetLevel::Economy
+    } else {
+        BudgetLevel::Normal
+    }
+}
+
+fn today_kst() -> NaiveDate {
+    Utc::now()
+        .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+        .date_naive()
+}
+
+fn sum_cost<'a>(usages: impl Iterator<Item = &'a LlmUsage>) -> f64 {
+    usages.map(|u| u.estimated_cost.unwrap_or_default()).sum()
+}
+
+async fn find_usages_since(
+    pool: &MySqlPool,
+    since: NaiveDate,
+) -> Result<Vec<LlmUsage>, OmniNewsError> {
+    llm_usage_repository::select_llm_usages_since(pool, since)
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to select llm usages: {:?}", e);
+            OmniNewsError::Database(e)
+        })
+}
+
+/// 오늘/이번 달 누적 비용으로 현재 예산 단계를 계산함.
+pub async fn get_budget_level(pool: &MySqlPool) -> Result<BudgetLevel, OmniNewsError> {
+    let today = today_kst();
+    let month_start = today.with_day(1).unwrap_or(today);
+    let usages = find_usages_since(pool, month_start).await?;
+
+    let today_cost = sum_cost(usages.iter().filter(|u| u.usage_date == Some(today)));
+    let month_cost = sum_cost(usages.iter());
+
+    Ok(Budget::from_env().level(today_cost, month_cost))
+}
+
+/// 요약 결과의 토큰 사용량과 예상 비용을 일별 원장에 누적함.
+/// 검증에 실패해 버려진 응답의 사용량도 함께 기록함.
+pub async fn record_usage(pool: &MySqlPool, summary: &Summary) -> Result<(), OmniNewsError> {
+    let usages = std::iter::once((&summary.provider, &summary.model, &summary.usage)).chain(
+        summary
+            .discarded
+            .iter()
+            .map(|d| (&d.provider, &d.model, &d.usage)),
+    );
+    upsert_usages(pool, usages).await
+}
+
+/// 모든 provider가 실패해 요약 결과가 없을 때 버려진 응답의 사용량만 기록함.
+pub async fn record_discarded_usage(
+    pool: &MySqlPool,
+    discarded: &[DiscardedUsage],
+) -> Result<(), OmniNewsError> {
+    let usages = discarded.iter().map(|d| (&d.provider, &d.model, &d.usage));
+    upsert_usages(pool, usages).await
+}
+
+async fn upsert_usages<'a>(
+    pool: &MySqlPool,
+    usages: impl Iterator<Item = (&'a String, &'a String, &'a TokenUsage)>,
+) -> Result<(), OmniNewsError> {
+    for (provider, model, token_usage) in usages {
+        let usage = NewLlmUsage {
+            usage_date: today_kst(),
+            provider: provider.clone(),
+            model: model.clone(),
+            input_tokens: token_usage.input_tokens,
+            output_tokens: token_usage.output_tokens,
+            estimated_cost: estimate_cost_krw(model, token_usage),
+        };
+
+        if let Err(e) = llm_usage_repository::upsert_llm_usage(pool, &usage).await {
+            news_warn!("[Service] Failed to record llm usage: {:?}", e);
+            return Err(OmniNewsError::Database(e));
+        }
+    }
+    Ok(())
+}
+
+/// 최근 `days`일 사용량과 예산 현황.
+pub async fn get_usage_report(
+    pool: &MySqlPool,
+    days: i64,
+) -> Result<LlmUsageReport, OmniNewsError> {
+    let today = today_kst();
+    let month_start = today.with_day(1).unwrap_or(today);
+    let since = (today - Duration::days(days.max(1) - 1)).min(month_start);
+    let usages = find_usages_since(pool, since).await?;
+
+    let budget = Budget::from_env();
+    let today_cost = sum_cost(usages.iter().filter(|u| u.usage_date == Some(today)));
+    let month_cost = sum_cost(
+        usages
+            .iter()
+            .filter(|u| u.usage_date.is_some_and(|d| d >= month_start)),
+    );
+    let report_since = today - Duration::days(days.max(1) - 1);
+
+    Ok(LlmUsageReport {
+        budget_level: budget.level(today_cost, month_cost),
+        daily_budget: budget.daily,
+        monthly_budget: budget.monthly,
+        today_cost,
+        month_cost,
+        usages: usages
+            .into_iter()
+            .filter(|u| u.usage_date.is_some_and(|d| d >= report_since))
+            .collect(),
+    })
+}
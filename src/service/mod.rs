@@ -1,4 +1,15 @@
+pub mod analysis_service;
+pub mod embedding_migration_service;
 pub mod embedding_service;
+pub mod index_evaluation_service;
+pub mod integrity_service;
+pub mod llm_usage_service;
+pub mod news_cluster_service;
 pub mod news_service;
+pub mod news_source;
+pub mod recommendation_service;
+pub mod retention_service;
 pub mod rss;
+pub mod search_service;
+pub mod summary_cache_service;
 pub mod user_service;
@@ -0,0 +1,156 @@
+use sqlx::MySqlPool;
+
+use crate::{
+    config::env::get_env_or,
+    model::error::OmniNewsError,
+    news_error, news_info,
+    repository::{news_cluster_repository, news_repository},
+    utils::embedding_util::{cosine_similarity, decode_embedding},
+};
+
+#[derive(Debug, Clone)]
+pub struct ClusterCandidate {
+    pub news_id: i32,
+    pub news_cluster_id: Option<i32>,
+    // 같은 사건의 뉴스가 재사용하는 분석 결과
+    pub news_summary: Option<String>,
+    pub news_keywords: Option<String>,
+    pub news_entities: Option<String>,
+    pub news_sentiment: Option<String>,
+    pub news_analysis_category: Option<String>,
+    pub embedding: Vec<f32>,
+}
+
+/// 최근 뉴스 임베딩을 메모리에 올려두고, 새로 들어온 뉴스와 코사인 유사도로 비교함.
+/// 한 번의 뉴스 패치 동안 유지되며, 새로 저장된 뉴스도 후보로 추가됨.
+pub struct NewsClusterIndex {
+    candidates: Vec<ClusterCandidate>,
+    threshold: f32,
+}
+
+impl NewsClusterIndex {
+    pub fn empty() -> Self {
+        Self {
+            candidates: Vec::new(),
+            threshold: get_env_or("NEWS_CLUSTER_SIMILARITY_THRESHOLD", 0.85),
+        }
+    }
+
+    /// 쿼리 벡터와 같은 텍스트(제목 + 설명)와 같은 모델 버전으로 만든 클러스터 비교용 임베딩만 불러옴.
+    pub async fn load(
+        pool: &MySqlPool,
+        embedding_model_version: i32,
+    ) -> Result<Self, OmniNewsError> {
+        let window_hours = get_env_or("NEWS_CLUSTER_WINDOW_HOURS", 24_i64);
+        let rows = news_cluster_repository::select_recent_news_embeddings(
+            pool,
+            window_hours,
+            embedding_model_version,
+        )
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to select recent news embeddings: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+        let mut index = Self::empty();
+        index.candidates = rows
+            .into_iter()
+            .filter_map(|row| {
+                Some(ClusterCandidate {
+                    news_id: row.news_id?,
+                    news_cluster_id: row.news_cluster_id,
+                    news_summary: row.news_summary,
+                    news_keywords: row.news_keywords,
+                    news_entities: row.news_entities,
+                    news_sentiment: row.news_sentiment,
+                    news_analysis_category: row.news_analysis_category,
+                    embedding: decode_embedding(row.embedding_value.as_ref()?),
+                })
+            })
+            .collect();
+
+        news_info!(
+            "[Service] Loaded {} recent news embeddings for clustering",
+            index.candidates.len()
+        );
+        Ok(index)
+    }
+
+    /// 임계값 이상인 후보 중 가장 유사한 뉴스의 인덱스를 반환함.
+    pub fn find_most_similar(&self, embedding: &[f32]) -> Option<usize> {
+        self.candidates
+            .iter()
+            .enumerate()
+            .map(|(i, candidate)| (i, cosine_similarity(&candidate.embedding, embedding)))
+            .filter(|(_, similarity)| *similarity >= self.threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&ClusterCandidate> {
+        self.candidates.get(index)
+    }
+
+    pub fn push(&mut self, candidate: ClusterCandidate) {
+        self.candidates.push(candidate);
+    }
+
+    /// 유사한 뉴스가 속한 클러스터 id를 반환함.
+    /// 아직 클러스터가 없다면 유사한 뉴스를 대표 기사로 하는 클러스터를 새로 만듦.
+    /// 새 뉴스가 저장되기 전이므로 클러스터 크기는 저장 후 `increase_cluster_size`로 늘림.
+    pub async fn resolve_cluster_id(
+        &mut self,
+        pool: &MySqlPool,
+        index: usize,
+    ) -> Result<i32, OmniNewsError> {
+        let candidate = self
+            .candidates
+            .get(index)
+            .cloned()
+            .ok_or_else(|| OmniNewsError::NotFound("news cluster candidate".to_string()))?;
+
+        let cluster_id = match candidate.news_cluster_id {
+            Some(cluster_id) => cluster_id,
+            None => {
+                let cluster_id =
+                    news_cluster_repository::insert_news_cluster(pool, candidate.news_id)
+                        .await
+                        .map_err(|e| {
+                            news_error!("[Service] Failed to insert news cluster: {:?}", e);
+                            OmniNewsError::Database(e)
+                        })?;
+                news_repository::update_news_cluster_id(pool, candidate.news_id, cluster_id)
+                    .await
+                    .map_err(|e| {
+                        news_error!("[Service] Failed to update news cluster id: {:?}", e);
+                        OmniNewsError::Database(e)
+                    })?;
+
+                // 클러스터가 없던 후보 뉴스 자신만 새 클러스터를 가리키도록 후보 목록을 갱신함.
+                // 같은 뉴스가 다시 후보로 매칭되어도 클러스터를 또 만들지 않음
+                for c in self
+                    .candidates
+                    .iter_mut()
+                    .filter(|c| c.news_id == candidate.news_id)
+                {
+                    c.news_cluster_id = Some(cluster_id);
+                }
+                cluster_id
+            }
+        };
+
+        Ok(cluster_id)
+    }
+}
+
+/// 클러스터에 뉴스가 하나 저장된 뒤 크기를 늘림.
+pub async fn increase_cluster_size(pool: &MySqlPool, cluster_id: i32) -> Result<(), OmniNewsError> {
+    news_cluster_repository::increase_news_cluster_size(pool, cluster_id)
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to increase news cluster size: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+    Ok(())
+}
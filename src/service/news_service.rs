@@ -1,35 +1,43 @@
-use std::collections::HashMap;
-
 use crate::{
+    config::env::get_env_or,
     global::FETCH_FLAG,
-    model::{error::OmniNewsError, news::NewNews},
+    model::{
+        analysis::ArticleAnalysis, embedding::NewEmbedding, error::OmniNewsError, news::NewNews,
+    },
     news_error, news_info, news_warn,
     repository::news_repository,
-    utils::api::query_gemini_summarize,
+    service::{
+        analysis_service, embedding_service,
+        news_cluster_service::{increase_cluster_size, ClusterCandidate, NewsClusterIndex},
+        news_source::{
+            article_extractor::extract_article_content,
+            google::{is_google_news_link, resolve_google_news_link},
+            structured::{parse_open_graph, OpenGraph},
+            yield_monitor::notify_yield_status,
+            NewsSourceRegistry,
+        },
+    },
+    utils::{
+        embedding_util::{embedding_sentence, encode_embedding, EmbeddingService},
+        summarizer::SummarizerChain,
+    },
 };
-use chrono::{Duration, FixedOffset, NaiveDateTime, Utc};
-use regex::Regex;
 use reqwest::Client;
-use scraper::{Html, Selector};
+use scraper::Html;
 use sqlx::MySqlPool;
 use tokio::task;
 
-type NewsType = HashMap<String, i32>;
-
-pub async fn delete_old_news(pool: &MySqlPool) -> Result<i32, OmniNewsError> {
-    match news_repository::delete_old_news(pool).await {
-        Ok(count) => Ok(count),
-        Err(e) => {
-            news_error!("[Service] Failed to delete old news: {:?}", e);
-            Err(OmniNewsError::Database(e))
-        }
-    }
-}
-
-pub async fn crawl_news_and_store_every_5_minutes(pool: &MySqlPool) -> Result<(), OmniNewsError> {
-    let news_type = set_news_type();
-
-    match fetch_news_and_store(pool, news_type.clone()).await {
+// 요약할 본문의 최대 글자 수. 한글은 UTF-8에서 글자당 3바이트이므로 바이트가 아닌 글자 수로 셈.
+const MAX_ANALYSIS_CONTENT_CHARS: usize = 3000;
+
+/// 수집 주기가 돌아온 뉴스 소스들에서 뉴스를 가져와 저장함.
+pub async fn crawl_due_news_and_store(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    summarizer: &SummarizerChain,
+    registry: &NewsSourceRegistry,
+) -> Result<(), OmniNewsError> {
+    match fetch_news_and_store(pool, embedding_service, summarizer, registry).await {
         Ok(_) => Ok(()),
         Err(e) => {
             news_error!("[Service] Failed to fetch news: {:?}", e);
@@ -38,82 +46,205 @@ pub async fn crawl_news_and_store_every_5_minutes(pool: &MySqlPool) -> Result<()
     }
 }
 
-/*
-* 정치 : 100
-* 경제 : 101
-* 사회 : 102
-* 생활/문화 : 103
-* 세계 : 104
-* IT/과학 : 105
-*/
-fn set_news_type() -> NewsType {
-    let mut news_type = HashMap::new();
-    news_type.insert("정치".to_string(), 100);
-    news_type.insert("경제".to_string(), 101);
-    news_type.insert("사회".to_string(), 102);
-    news_type.insert("생활/문화".to_string(), 103);
-    news_type.insert("세계".to_string(), 104);
-    news_type.insert("IT/과학".to_string(), 105);
-    news_type.insert("주요".to_string(), 999);
-
-    news_type
-}
-
-async fn fetch_news_and_store(pool: &MySqlPool, news_type: NewsType) -> Result<(), OmniNewsError> {
+async fn fetch_news_and_store(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    summarizer: &SummarizerChain,
+    registry: &NewsSourceRegistry,
+) -> Result<(), OmniNewsError> {
+    let sources = registry.due_sources();
     let client = Client::new();
-
-    for (subject, code) in &news_type {
-        // 주요뉴스 빼오기. -> 구글뉴스 사용.
-        let site = if *code == 999 {
-            "https://news.google.com/topics/CAAqJggKIiBDQkFTRWdvSUwyMHZNRFZxYUdjU0FtdHZHZ0pMVWlnQVAB?hl=ko&gl=KR&ceid=KR%3Ako".to_string()
-        } else {
-            format!("https://news.naver.com/section/{}", code)
+    // 최근 뉴스 임베딩과 비교해 같은 사건을 다루는 기사는 하나의 클러스터로 묶음
+    let mut cluster_index = if sources.is_empty() {
+        NewsClusterIndex::empty()
+    } else {
+        NewsClusterIndex::load(pool, embedding_service.model_version())
+            .await
+            .unwrap_or_else(|e| {
+                news_warn!("[Service] Failed to load news cluster index: {}", e);
+                NewsClusterIndex::empty()
+            })
+    };
+
+    for source in sources {
+        let config = source.config();
+        let fetched = match source.fetch(&client).await {
+            Ok(fetched) => {
+                registry.mark_fetched(&config.name);
+                fetched
+            }
+            Err(e) => {
+                // 한 소스가 실패해도 나머지 소스는 계속 수집함
+                news_warn!("[Service] Failed to fetch news from {}: {}", config.name, e);
+                continue;
+            }
         };
 
-        let res = client.get(site).send().await?.text().await?;
-
-        let document = Html::parse_document(&res);
-
-        let mut newsses = make_news(document, subject, *code);
-        // 헤드라인 뉴스 10개는 사용 안함.
-        let _ = newsses.drain(0..9);
-
-        for mut news in newsses {
-            match news_repository::select_news_by_title(pool, news.news_title.clone().unwrap())
-                .await
+        let report = &fetched.report;
+        news_info!(
+            "[Service] {} parsed {} news with {} (skipped: {}, fallbacks: {:?})",
+            config.name,
+            report.parsed,
+            report.strategy,
+            report.skipped,
+            report.fallbacks
+        );
+        if report.parsed == 0 {
+            news_warn!("[Service] No news parsed from {}", config.name);
+        }
+        // 파싱 개수가 평소보다 급감하면 마크업 변경으로 보고 알림
+        let status = registry.yield_monitor().record(&config.name, report.parsed);
+        notify_yield_status(&client, &config.name, &status).await;
+
+        // 헤드라인 뉴스는 사용 안함.
+        for mut news in fetched.into_newsses(config.skip_headlines) {
+            match news_repository::select_news_by_title(
+                pool,
+                news.news_title.as_deref().unwrap_or_default(),
+            )
+            .await
             {
-                Ok(_) => (),
-                Err(_) => {
-                    // 발행일 있는 뉴스만 다룸
-                    if news.news_pub_date.is_none() {
-                        continue;
-                    }
+                // 이미 저장된 뉴스
+                Ok(_) => continue,
+                Err(sqlx::Error::RowNotFound) => (),
+                Err(e) => {
+                    // 조회에 실패한 뉴스를 새 뉴스로 보면 중복 저장과 분석 비용이 생기므로 건너뜀
+                    news_error!("[Service] Failed to select news by title: {:?}", e);
+                    continue;
+                }
+            }
+
+            // 구글 뉴스 링크는 언론사 원문 URL로 바꿔 저장하고 본문 추출에 사용함
+            let news_link = news.news_link.clone().unwrap_or_default();
+            if is_google_news_link(&news_link) {
+                match resolve_google_news_link(&client, &news_link).await {
+                    Some(url) => news.news_link = Some(url),
+                    None => news_warn!("[Service] Failed to find publisher url for {}", news_link),
+                }
+            }
 
-                    match summarize_news(
-                        news.news_link.clone().unwrap().as_str(),
-                        news.news_description.clone().unwrap().as_str(),
-                    )
+            // 목록에서 발행일을 알 수 없으면 기사 페이지의 article:published_time을 사용하고,
+            // 그래도 없으면 버림. 내려받은 페이지는 요약에도 그대로 사용함
+            let mut article = None;
+            if news.news_pub_date.is_none() {
+                match fetch_article_page(&client, news.news_link.as_deref().unwrap_or_default())
                     .await
-                    {
-                        Ok(res) => {
-                            news.news_summary = Some(res);
-                        }
-                        Err(e) => {
-                            // 요약 실패 시 기본 설명으로 넣음.
-                            news_warn!("[Service] Failed to summarize news. {}", e);
-                            news.news_summary =
-                                Some(news.news_description.clone().unwrap_or_default());
-                        }
+                {
+                    Ok(page) => {
+                        news.news_pub_date = page.open_graph.published_time;
+                        article = Some(page);
+                    }
+                    Err(e) => {
+                        news_warn!("[Service] Failed to fetch article for publish time: {}", e)
                     }
+                }
+                if news.news_pub_date.is_none() {
+                    news_warn!(
+                        "[Service] Dropped news without publish time: {}",
+                        news.news_link.clone().unwrap_or_default()
+                    );
+                    continue;
+                }
+            }
+
+            // 클러스터 비교용 임베딩은 요약 전이므로 제목 + 설명으로 생성하고,
+            // 다음 뉴스와 같은 텍스트로 비교하도록 뉴스와 함께 저장함.
+            let embedding_text = format!(
+                "{}\n{}",
+                news.news_title.clone().unwrap_or_default(),
+                news.news_description.clone().unwrap_or_default()
+            );
+            let query_embedding = match embedding_sentence(embedding_service, embedding_text).await
+            {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    news_warn!("[Service] Failed to embed news: {}", e);
+                    None
+                }
+            };
 
-                    let _ = news_repository::insert_news(pool, news.clone())
-                        .await
-                        .map_err(|e| {
-                            news_error!("[Service] Failed to insert news: {:?}", e);
-                            OmniNewsError::Database(e)
-                        });
+            if let Some(value) = &query_embedding {
+                news.news_cluster_embedding = Some(encode_embedding(value));
+                news.news_cluster_embedding_version = Some(embedding_service.model_version());
+            }
+
+            let similar = query_embedding
+                .as_ref()
+                .and_then(|value| cluster_index.find_most_similar(value));
+            if let Some(index) = similar {
+                match cluster_index.resolve_cluster_id(pool, index).await {
+                    Ok(cluster_id) => news.news_cluster_id = Some(cluster_id),
+                    Err(e) => news_warn!("[Service] Failed to resolve news cluster: {}", e),
+                }
+            }
+
+            let similar_candidate = similar
+                .and_then(|index| cluster_index.get(index))
+                .filter(|candidate| candidate.news_summary.is_some());
+
+            match similar_candidate {
+                // 이미 요약된 같은 사건의 기사가 있으면 요약과 분석 결과를 재사용해 LLM 호출을 아낌.
+                Some(candidate) => {
+                    news.news_summary = candidate.news_summary.clone();
+                    news.news_keywords = candidate.news_keywords.clone();
+                    news.news_entities = candidate.news_entities.clone();
+                    news.news_sentiment = candidate.news_sentiment.clone();
+                    news.news_analysis_category = candidate.news_analysis_category.clone();
+                }
+                None => match analyze_news(pool, summarizer, &client, &mut news, article).await {
+                    Ok(Some(analysis)) => {
+                        news.apply_analysis(&analysis);
+                    }
+                    Ok(None) => {
+                        news.news_summary = Some(news.news_description.clone().unwrap_or_default());
+                    }
+                    Err(e) => {
+                        // 요약 실패 시 기본 설명으로 넣음.
+                        news_warn!("[Service] Failed to summarize news. {}", e);
+                        news.news_summary = Some(news.news_description.clone().unwrap_or_default());
+                    }
+                },
+            }
+
+            let news_id = match news_repository::insert_news(pool, news.clone()).await {
+                Ok(news_id) => news_id,
+                Err(e) => {
+                    news_error!("[Service] Failed to insert news: {:?}", e);
+                    continue;
                 }
             };
+            // 저장에 성공한 뉴스만 클러스터 크기에 반영함
+            if let Some(cluster_id) = news.news_cluster_id {
+                if let Err(e) = increase_cluster_size(pool, cluster_id).await {
+                    news_warn!("[Service] Failed to update news cluster size: {}", e);
+                }
+            }
+
+            if let Some(embedding) = query_embedding {
+                cluster_index.push(ClusterCandidate {
+                    news_id,
+                    news_cluster_id: news.news_cluster_id,
+                    news_summary: news.news_summary.clone(),
+                    news_keywords: news.news_keywords.clone(),
+                    news_entities: news.news_entities.clone(),
+                    news_sentiment: news.news_sentiment.clone(),
+                    news_analysis_category: news.news_analysis_category.clone(),
+                    embedding,
+                });
+            }
+
+            // 저장용 임베딩은 제목 + 요약으로 생성해 뉴스 검색/추천에 사용함.
+            if let Err(e) = embed_and_store_news(
+                pool,
+                embedding_service,
+                news_id,
+                &news.news_title.clone().unwrap_or_default(),
+                &news.news_summary.clone().unwrap_or_default(),
+            )
+            .await
+            {
+                news_warn!("[Service] Failed to store news embedding: {}", e);
+            }
         }
     }
     // 뉴스 패치가 끝났음으로 fetch_flag를 true로 설정
@@ -128,227 +259,214 @@ async fn fetch_news_and_store(pool: &MySqlPool, news_type: NewsType) -> Result<(
     Ok(())
 }
 
-fn make_news(document: Html, subject: &String, code: i32) -> Vec<NewNews> {
-    if code == 999 {
-        return make_google_news(document, subject);
-    }
+/// 뉴스 제목과 요약으로 임베딩을 생성해 news_id와 연결해 저장함.
+/// 같은 텍스트의 임베딩이 이미 있으면 모델을 호출하지 않고 재사용함.
+async fn embed_and_store_news(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    news_id: i32,
+    title: &str,
+    summary: &str,
+) -> Result<(), OmniNewsError> {
+    let sentence = format!("{title}\n{summary}");
+    let embedding = NewEmbedding {
+        embedding_value: None,
+        channel_id: None,
+        rss_id: None,
+        news_id: Some(news_id),
+        embedding_source_rank: Some(0),
+        embedding_model_id: None,
+        embedding_model_version: None,
+        embedding_content_hash: None,
+    };
+    embedding_service::create_embedding(pool, embedding_service, sentence, embedding).await?;
 
-    let news_selector = Selector::parse(".sa_item_flex").unwrap();
-    let title_selector = Selector::parse(".sa_text_strong").unwrap();
-    let description_selector = Selector::parse(".sa_text_lede").unwrap();
-    let link_selector = Selector::parse(".sa_thumb_link").unwrap();
-    let source_selector = Selector::parse(".sa_text_press").unwrap();
-    let pub_date_selector = Selector::parse(".sa_text_datetime > b").unwrap();
-    let image_link_selector = Selector::parse(".sa_thumb_link > img").unwrap();
-
-    document
-        .select(&news_selector)
-        .map(|news| NewNews {
-            news_title: Some(
-                news.select(&title_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_description: Some(
-                news.select(&description_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_summary: None,
-            news_link: Some(
-                news.select(&link_selector)
-                    .next()
-                    .map(|e| e.attr("href").unwrap())
-                    .unwrap_or_default()
-                    .to_string(),
-            ),
-            news_source: Some(
-                news.select(&source_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_pub_date: pub_date_to_naive_time(
-                news.select(&pub_date_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_image_link: Some(
-                news.select(&image_link_selector)
-                    .next()
-                    .map(|e| e.attr("data-src").unwrap_or_default())
-                    .unwrap_or_default()
-                    .to_string(),
-            ),
-            news_category: Some(subject.to_string()),
-        })
-        .collect::<Vec<NewNews>>()
+    Ok(())
 }
 
-fn make_google_news(document: Html, subject: &String) -> Vec<NewNews> {
-    let news_selector = Selector::parse(".LU3Rqb").unwrap();
-    let title_selector = Selector::parse(".gPFEn").unwrap();
-    let description_selector = Selector::parse(".gPFEn").unwrap();
-    let link_selector = Selector::parse(".WwrzSb").unwrap();
-    let source_selector = Selector::parse(".vr1PYe").unwrap();
-    let pub_date_selector = Selector::parse(".IBr9hb > .UOVeFe > .hvbAAd").unwrap();
-    let image_link_selector = Selector::parse(".Quavad.vwBmvb").unwrap();
-
-    document
-        .select(&news_selector)
-        .map(|news| NewNews {
-            news_title: Some(
-                news.select(&title_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_description: Some(
-                news.select(&description_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_summary: None,
-            news_link: Some(
-                news.select(&link_selector)
-                    .next()
-                    .map(|e| e.attr("href").unwrap())
-                    .map(|value| format!("https://news.google.com/{}", value))
-                    .unwrap_or_default()
-                    .to_string(),
-            ),
-            news_source: Some(
-                news.select(&source_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_pub_date: pub_date_to_naive_time(
-                news.select(&pub_date_selector)
-                    .next()
-                    .map(|e| e.inner_html())
-                    .unwrap_or_default(),
-            ),
-            news_image_link: Some(
-                news.select(&image_link_selector)
-                    .next()
-                    .map(|e| e.attr("src").unwrap_or_default())
-                    .map(|value| format!("https://news.google.com{}", value))
-                    .unwrap_or_default()
-                    .to_string(),
+/// 현재 모델 버전의 임베딩이 없는 뉴스들의 임베딩을 배치 단위로 생성함.
+/// 모델 버전이 바뀐 경우 기존 뉴스의 재임베딩에도 사용됨.
+pub async fn backfill_news_embeddings(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+) -> Result<i32, OmniNewsError> {
+    let batch_size = get_env_or("NEWS_EMBEDDING_BACKFILL_BATCH_SIZE", 100_i64);
+    let mut last_news_id = 0;
+    let mut created = 0;
+    let embedding_model_version = embedding_service.model_version();
+
+    loop {
+        let newsses = news_repository::select_news_without_embedding(
+            pool,
+            embedding_model_version,
+            last_news_id,
+            batch_size,
+        )
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to select news without embedding: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+        if newsses.is_empty() {
+            break;
+        }
+
+        // 실패한 뉴스를 다시 조회하지 않도록 커서는 항상 전진시킴
+        last_news_id = newsses
+            .iter()
+            .filter_map(|news| news.news_id)
+            .max()
+            .unwrap_or(last_news_id);
+
+        let sources = newsses
+            .into_iter()
+            .filter_map(|news| {
+                let news_id = news.news_id?;
+                // 요약이 없으면 설명으로 대신함
+                let summary = news
+                    .news_summary
+                    .filter(|summary| !summary.is_empty())
+                    .or(news.news_description)
+                    .unwrap_or_default();
+                let embedding = NewEmbedding {
+                    embedding_value: None,
+                    channel_id: None,
+                    rss_id: None,
+                    news_id: Some(news_id),
+                    embedding_source_rank: Some(0),
+                    embedding_model_id: None,
+                    embedding_model_version: None,
+                    embedding_content_hash: None,
+                };
+                Some((
+                    format!("{}\n{}", news.news_title.unwrap_or_default(), summary),
+                    embedding,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        match embedding_service::create_embeddings(pool, embedding_service, sources).await {
+            Ok(count) => created += count,
+            Err(e) => news_warn!(
+                "[Service] Failed to backfill embeddings for news up to {}: {}",
+                last_news_id,
+                e
             ),
-            news_category: Some(subject.to_string()),
-        })
-        .collect::<Vec<NewNews>>()
-}
+        }
+    }
 
-fn pub_date_to_naive_time(pub_date: String) -> Option<NaiveDateTime> {
-    let pub_date = pub_date.replace(" ", "");
-    let current_time = chrono::Local::now();
+    Ok(created)
+}
 
-    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+// 뉴스는 `MAX_ANALYSIS_CONTENT_CHARS`자 이하만 요약시킴.
+// 긴 본문은 입력 토큰 비용이 크므로 길이로 한 번 거르고, 전체 비용은 llm_usage 원장과 일/월 예산으로 제한함.
+// 본문을 분석하지 못한 경우 `None`을 반환하며, 호출부에서 기본 설명을 요약으로 사용함.
+// 목록에서 설명이나 이미지를 얻지 못한 뉴스는 기사 페이지의 OpenGraph 정보로 채움.
+// 발행일 조회로 이미 내려받은 페이지가 있으면 다시 요청하지 않음.
+async fn analyze_news(
+    pool: &MySqlPool,
+    summarizer: &SummarizerChain,
+    client: &Client,
+    news: &mut NewNews,
+    article: Option<ArticlePage>,
+) -> Result<Option<ArticleAnalysis>, OmniNewsError> {
+    let news_link = news.news_link.clone().unwrap_or_default();
+    if is_google_news_link(&news_link) {
+        // 원문 URL을 찾지 못한 구글 뉴스는 요약하지 않음.
+        return Ok(None);
+    }
 
-    if pub_date.is_empty() {
-        return None;
+    let ArticlePage {
+        content,
+        open_graph,
+    } = match article {
+        Some(article) => article,
+        None => fetch_article_page(client, &news_link).await?,
+    };
+
+    if news
+        .news_description
+        .as_deref()
+        .unwrap_or_default()
+        .is_empty()
+    {
+        if let Some(description) = open_graph.description {
+            news.news_description = Some(description);
+        }
+    }
+    if news
+        .news_image_link
+        .as_deref()
+        .unwrap_or_default()
+        .is_empty()
+    {
+        if let Some(image) = open_graph.image {
+            news.news_image_link = Some(image);
+        }
     }
 
-    // 한글은 글자 당 3바이트
-    if pub_date.ends_with("분전") {
-        let (value, _) = pub_date.split_at(pub_date.len() - 6);
-        Some(
-            NaiveDateTime::parse_from_str(
-                &current_time
-                    .with_timezone(&kst)
-                    .checked_sub_signed(Duration::minutes(value.parse::<i64>().unwrap_or_default()))
-                    .unwrap_or_default()
-                    .format("%Y-%m-%d %H:%M:%S")
-                    .to_string(),
-                "%Y-%m-%d %H:%M:%S",
-            )
-            .unwrap_or_default(),
-        )
-    } else if pub_date.ends_with("시간전") {
-        let (value, _) = pub_date.split_at(pub_date.len() - 9);
-        Some(
-            NaiveDateTime::parse_from_str(
-                &current_time
-                    .with_timezone(&kst)
-                    .checked_sub_signed(Duration::hours(value.parse::<i64>().unwrap_or_default()))
-                    .unwrap_or_default()
-                    .format("%Y-%m-%d %H:%M:%S")
-                    .to_string(),
-                "%Y-%m-%d %H:%M:%S",
-            )
-            .unwrap_or_default(),
-        )
-    } else if pub_date == "어제" {
-        Utc::now()
-            .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
-            .checked_sub_signed(Duration::days(1))
-            .map(|dt| dt.naive_local())
-    } else {
-        None
+    // 본문을 찾지 못했거나 너무 긴 뉴스는 요약 X
+    if content.is_empty() || content.chars().count() > MAX_ANALYSIS_CONTENT_CHARS {
+        return Ok(None);
     }
+
+    analysis_service::analyze_content(pool, summarizer, content).await
 }
 
-// 뉴스는 약 3000자 미만만 요약시킴.
-// 뉴스가 너무 많이 올라올 때 어떻게 할지 금액 보면서 조치하기.
-// 10/15 하루에 33만원나옴 ㅋ;
-async fn summarize_news(news_link: &str, news_description: &str) -> Result<String, OmniNewsError> {
-    if news_link.contains("google.com") {
-        // 구글 뉴스는 요약하지 않음.
-        return Ok(news_description.into());
+/// 기사 페이지에서 꺼낸 본문과 OpenGraph 정보.
+struct ArticlePage {
+    content: String,
+    open_graph: OpenGraph,
+}
+
+/// 기사 페이지를 한 번 내려받아 본문과 OpenGraph 정보를 파싱함.
+async fn fetch_article_page(
+    client: &Client,
+    news_link: &str,
+) -> Result<ArticlePage, OmniNewsError> {
+    if news_link.is_empty() {
+        return Err(OmniNewsError::FetchUrl);
     }
-    let client = Client::new();
 
     let res = client.get(news_link).send().await?.text().await?;
+    let news_link = news_link.to_string();
 
     // HTML 파싱 동기 작업을 spawn_blocking으로 감싸서 별도 스레드에서 실행
-    let content = task::spawn_blocking(move || {
+    task::spawn_blocking(move || {
         let document = Html::parse_document(&res);
-        let news_selector = Selector::parse("#dic_area").unwrap();
-
-        document
-            .select(&news_selector)
-            .next()
-            .map(|element| {
-                // <br> 태그를 줄바꿈으로 변환
-                let html_with_newlines =
-                    element.html().replace("<br>", "\n").replace("<br/>", "\n");
-
-                // 모든 HTML 태그 제거
-                let re = Regex::new(r"<[^>]*>").unwrap();
-                let text = re.replace_all(&html_with_newlines, "");
-
-                // HTML 엔티티 디코딩
-                let decoded = html_escape::decode_html_entities(&text);
-
-                // 연속된 공백과 줄바꿈 정리
-                decoded
-                    .split('\n')
-                    .map(|line| line.trim())
-                    .filter(|line| !line.is_empty())
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            })
-            .unwrap_or_default()
+        ArticlePage {
+            content: extract_article_content(&document, &news_link),
+            open_graph: parse_open_graph(&document),
+        }
     })
     .await
     .map_err(|e| {
         news_error!("[Service] spawn_blocking failed: {:?}", e);
         OmniNewsError::FetchNews
-    })?;
+    })
+}
 
-    // 너무 긴 뉴스는 (약 3000자) 요약 X
-    if content.len() > 5000 {
-        return Ok(news_description.into());
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn parses_article_open_graph() {
+        let html = include_str!("../../tests/fixtures/naver_article.html");
+        let open_graph = parse_open_graph(&Html::parse_document(html));
+
+        assert_eq!(
+            open_graph.description.as_deref(),
+            Some("국회 예산결산특별위원회가 내년도 예산안에 대한 본격적인 심사에 들어갔다.")
+        );
+        assert!(open_graph.image.is_some());
+        assert_eq!(
+            open_graph.published_time,
+            NaiveDate::from_ymd_opt(2025, 10, 15)
+                .unwrap()
+                .and_hms_opt(14, 57, 0)
+        );
     }
-
-    let summary = query_gemini_summarize(50, &content).await;
-
-    Ok(summary)
 }
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+
+use reqwest::Url;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
+
+// 도메인별 본문 선택자. 호스트가 도메인과 같거나 하위 도메인이면 적용하며, 앞의 선택자부터 시도함.
+const DOMAIN_RULES: &[(&str, &[&str])] = &[
+    (
+        "news.naver.com",
+        &["#dic_area", "#articeBody", "#newsEndContents"],
+    ),
+    ("v.daum.net", &[".article_view section", ".article_view"]),
+    (
+        "yna.co.kr",
+        &[".story-news.article", "#articleWrap .article"],
+    ),
+    ("chosun.com", &["section.article-body"]),
+    ("joongang.co.kr", &["#article_body"]),
+    ("donga.com", &["section.news_view", ".article_txt"]),
+    ("hani.co.kr", &[".article-text"]),
+    ("khan.co.kr", &["#articleBody", ".art_body"]),
+    ("mk.co.kr", &[".news_cnt_detail_wrap"]),
+    ("hankyung.com", &["#articletxt"]),
+    ("sbs.co.kr", &[".text_area"]),
+    ("kbs.co.kr", &["#cont_newstext"]),
+    ("imbc.com", &[".news_txt"]),
+];
+
+// 도메인 규칙이 없을 때 시도하는 일반적인 본문 컨테이너
+const GENERIC_SELECTORS: &[&str] = &[
+    r#"[itemprop="articleBody"]"#,
+    "#articleBody",
+    "#article_body",
+    ".article_body",
+    ".article-body",
+    "article",
+];
+
+// 본문으로 인정하는 최소 글자 수. 이보다 짧으면 다음 방법을 시도함.
+const MIN_CONTENT_CHARS: usize = 100;
+// 문단 밀도 계산에서 무시하는 짧은 문단 (캡션, 버튼 등)
+const MIN_PARAGRAPH_CHARS: usize = 20;
+
+// 본문 텍스트에서 제외하는 태그
+const SKIPPED_TAGS: &[&str] = &[
+    "script",
+    "style",
+    "noscript",
+    "iframe",
+    "form",
+    "button",
+    "select",
+    "figcaption",
+    "nav",
+    "aside",
+    "header",
+    "footer",
+];
+
+// 줄바꿈으로 구분하는 블록 태그
+const BLOCK_TAGS: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "li",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "blockquote",
+    "tr",
+];
+
+/// 기사 페이지에서 본문을 줄 단위 텍스트로 추출함. 찾지 못하면 빈 문자열.
+/// 도메인 규칙 → JSON-LD `articleBody` → 일반 본문 선택자 → 문단 밀도 순으로 시도함.
+pub fn extract_article_content(document: &Html, url: &str) -> String {
+    if let Some(selectors) = domain_rule(url) {
+        if let Some(content) = first_match(document, selectors, 1) {
+            return content;
+        }
+    }
+
+    json_ld_article_body(document)
+        .filter(|content| content.chars().count() >= MIN_CONTENT_CHARS)
+        .or_else(|| first_match(document, GENERIC_SELECTORS, MIN_CONTENT_CHARS))
+        .or_else(|| densest_paragraphs(document))
+        .unwrap_or_default()
+}
+
+fn domain_rule(url: &str) -> Option<&'static [&'static str]> {
+    let url = Url::parse(url).ok()?;
+    let host = url.host_str()?;
+
+    DOMAIN_RULES
+        .iter()
+        .find(|(domain, _)| host == *domain || host.ends_with(&format!(".{}", domain)))
+        .map(|(_, selectors)| *selectors)
+}
+
+fn first_match(document: &Html, selectors: &[&str], min_chars: usize) -> Option<String> {
+    selectors.iter().find_map(|selector| {
+        let selector = Selector::parse(selector).ok()?;
+        document
+            .select(&selector)
+            .map(element_text)
+            .find(|content| content.chars().count() >= min_chars)
+    })
+}
+
+fn json_ld_article_body(document: &Html) -> Option<String> {
+    let script_selector = Selector::parse(r#"script[type="application/ld+json"]"#).unwrap();
+
+    document.select(&script_selector).find_map(|script| {
+        let value = serde_json::from_str::<Value>(&script.text().collect::<String>()).ok()?;
+        find_article_body(&value).map(|body| clean_lines(&html_escape::decode_html_entities(body)))
+    })
+}
+
+fn find_article_body(value: &Value) -> Option<&str> {
+    match value {
+        Value::Array(values) => values.iter().find_map(find_article_body),
+        Value::Object(object) => object
+            .get("articleBody")
+            .and_then(Value::as_str)
+            .or_else(|| object.get("@graph").and_then(find_article_body)),
+        _ => None,
+    }
+}
+
+// 긴 문단(<p>)을 가장 많이 가진 부모 요소를 본문으로 봄
+fn densest_paragraphs(document: &Html) -> Option<String> {
+    let paragraph_selector = Selector::parse("p").unwrap();
+
+    let mut scores = HashMap::new();
+    for paragraph in document.select(&paragraph_selector) {
+        let chars = paragraph.text().collect::<String>().trim().chars().count();
+        if chars < MIN_PARAGRAPH_CHARS {
+            continue;
+        }
+        let Some(parent) = paragraph.parent() else {
+            continue;
+        };
+        scores.entry(parent.id()).or_insert((parent, 0)).1 += chars;
+    }
+
+    scores
+        .into_values()
+        .max_by_key(|(_, chars)| *chars)
+        .filter(|(_, chars)| *chars >= MIN_CONTENT_CHARS)
+        .and_then(|(parent, _)| ElementRef::wrap(parent))
+        .map(element_text)
+}
+
+fn element_text(element: ElementRef) -> String {
+    let mut text = String::new();
+    collect_text(element, &mut text);
+    clean_lines(&text)
+}
+
+fn collect_text(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        if let Some(text) = child.value().as_text() {
+            out.push_str(text);
+            continue;
+        }
+        let Some(child) = ElementRef::wrap(child) else {
+            continue;
+        };
+
+        let name = child.value().name();
+        if SKIPPED_TAGS.contains(&name) {
+            continue;
+        }
+        if name == "br" {
+            out.push('\n');
+        } else if BLOCK_TAGS.contains(&name) {
+            out.push('\n');
+            collect_text(child, out);
+            out.push('\n');
+        } else {
+            collect_text(child, out);
+        }
+    }
+}
+
+// 연속된 공백과 빈 줄 정리
+fn clean_lines(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_naver_dic_area() {
+        let html = include_str!("../../../tests/fixtures/naver_article.html");
+        let content = extract_article_content(
+            &Html::parse_document(html),
+            "https://n.news.naver.com/mnews/article/001/0015000001",
+        );
+
+        assert_eq!(
+            content.lines().collect::<Vec<_>>(),
+            vec![
+                "(서울=연합뉴스) 홍길동 기자 = 국회 예산결산특별위원회가 15일 전체회의를 열고 내년도 예산안 심사에 착수했다.",
+                "위원회는 이날 정부 측으로부터 예산안 제출 설명을 들었다.",
+                "여야는 \"민생 예산\"을 우선 심사하기로 했다.",
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_unknown_publisher_by_paragraph_density() {
+        let html = include_str!("../../../tests/fixtures/publisher_article.html");
+        let content = extract_article_content(
+            &Html::parse_document(html),
+            "https://www.example-daily.co.kr/news/articleView.html?idxno=1",
+        );
+
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("정부가 내년부터"));
+        // 스크립트, 메뉴, 관련 기사, 사진 설명은 제외함
+        assert!(!content.contains("googletag"));
+        assert!(!content.contains("많이 본 뉴스"));
+        assert!(!content.contains("사진 제공"));
+    }
+
+    #[test]
+    fn prefers_json_ld_article_body_over_markup() {
+        let html = r#"<html><head><script type="application/ld+json">
+            {"@context":"https://schema.org","@graph":[{"@type":"WebPage"},{"@type":"NewsArticle",
+            "articleBody":"서울시가 내년부터 심야 자율주행 버스 노선을 강남과 홍대 일대로 확대한다고 15일 밝혔다. 시는 운행 시간도 새벽 5시까지 늘릴 계획이다.\n시 관계자는 &quot;안전성이 충분히 검증된 만큼 노선을 단계적으로 넓히겠다&quot;고 말했다."}]}
+            </script></head><body><div class="ad">광고</div></body></html>"#;
+        let content =
+            extract_article_content(&Html::parse_document(html), "https://news.example.com/1");
+
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.contains("관계자는 \"안전성이"));
+    }
+
+    #[test]
+    fn returns_empty_content_without_article() {
+        let document = Html::parse_document("<html><body><p>본문 없음</p></body></html>");
+        assert!(extract_article_content(&document, "https://news.example.com/1").is_empty());
+    }
+
+    #[test]
+    fn matches_domain_rules_by_host_suffix() {
+        assert_eq!(
+            domain_rule("https://n.news.naver.com/mnews/article/001/1"),
+            Some(&["#dic_area", "#articeBody", "#newsEndContents"][..])
+        );
+        assert_eq!(
+            domain_rule("https://www.chosun.com/politics/2025/10/15/"),
+            Some(&["section.article-body"][..])
+        );
+        assert!(domain_rule("https://notchosun.com/1").is_none());
+        assert!(domain_rule("not a url").is_none());
+    }
+}
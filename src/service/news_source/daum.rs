@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use reqwest::Client;
+use scraper::{Html, Selector};
+
+use crate::{
+    model::{error::OmniNewsError, news::NewNews},
+    utils::date_util::parse_news_date,
+};
+
+use super::{structured::parse_json_ld_news, FetchedNews, NewsSource, NewsSourceConfig};
+
+/// 다음 뉴스 카테고리 (`https://news.daum.net/{target}`, 예: `politics`, `economy`).
+pub struct DaumNewsSource {
+    config: NewsSourceConfig,
+}
+
+impl DaumNewsSource {
+    pub fn new(config: NewsSourceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl NewsSource for DaumNewsSource {
+    fn config(&self) -> &NewsSourceConfig {
+        &self.config
+    }
+
+    async fn fetch(&self, client: &Client) -> Result<FetchedNews, OmniNewsError> {
+        let site = format!("https://news.daum.net/{}", self.config.target);
+        let res = client.get(site).send().await?.text().await?;
+
+        Ok(parse_category(&res, &self.config.category))
+    }
+}
+
+/// JSON-LD를 우선 사용하고, 없으면 CSS 클래스로 파싱함.
+pub fn parse_category(html: &str, category: &str) -> FetchedNews {
+    let document = Html::parse_document(html);
+
+    FetchedNews::from_candidates("json-ld", parse_json_ld_news(&document, category))
+        .or_else(|| FetchedNews::from_candidates("css", make_daum_news(&document, category)))
+}
+
+fn make_daum_news(document: &Html, subject: &str) -> Vec<NewNews> {
+    let news_selector = Selector::parse(".list_newsheadline2 > li").unwrap();
+    let title_selector = Selector::parse(".tit_txt").unwrap();
+    let description_selector = Selector::parse(".desc_txt").unwrap();
+    let link_selector = Selector::parse("a.item_newsheadline2").unwrap();
+    // 언론사, 발행 시각 순으로 표시됨
+    let info_selector = Selector::parse(".txt_info").unwrap();
+    let image_link_selector = Selector::parse(".wrap_thumb img").unwrap();
+
+    document
+        .select(&news_selector)
+        .map(|news| {
+            let mut infos = news
+                .select(&info_selector)
+                .map(|e| e.text().collect::<String>().trim().to_string());
+
+            NewNews {
+                news_title: Some(
+                    news.select(&title_selector)
+                        .next()
+                        .map(|e| e.text().collect::<String>().trim().to_string())
+                        .unwrap_or_default(),
+                ),
+                news_description: Some(
+                    news.select(&description_selector)
+                        .next()
+                        .map(|e| e.text().collect::<String>().trim().to_string())
+                        .unwrap_or_default(),
+                ),
+                news_link: Some(
+                    news.select(&link_selector)
+                        .next()
+                        .and_then(|e| e.attr("href"))
+                        .unwrap_or_default()
+                        .to_string(),
+                ),
+                news_source: Some(infos.next().unwrap_or_default()),
+                news_pub_date: infos.next().and_then(|date| parse_news_date(&date)),
+                news_image_link: Some(
+                    news.select(&image_link_selector)
+                        .next()
+                        .and_then(|e| e.attr("src"))
+                        .unwrap_or_default()
+                        .to_string(),
+                ),
+                news_category: Some(subject.to_string()),
+                ..Default::default()
+            }
+        })
+        .collect::<Vec<NewNews>>()
+}
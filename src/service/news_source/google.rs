@@ -0,0 +1,330 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use reqwest::{Client, Url};
+use rss::Channel;
+use scraper::{Html, Selector};
+
+use crate::{
+    model::{error::OmniNewsError, news::NewNews},
+    news_warn,
+    service::rss::item_service::parse_pub_date,
+    utils::date_util::parse_news_date,
+};
+
+use super::{structured::parse_json_ld_news, FetchedNews, NewsSource, NewsSourceConfig};
+
+/// 구글 뉴스 토픽. `target`에 토픽 URL 전체를 설정함.
+/// 같은 토픽의 RSS(`/rss/topics/...`)를 우선 사용하고, 실패하면 토픽 페이지를 파싱함.
+pub struct GoogleNewsSource {
+    config: NewsSourceConfig,
+}
+
+impl GoogleNewsSource {
+    pub fn new(config: NewsSourceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl NewsSource for GoogleNewsSource {
+    fn config(&self) -> &NewsSourceConfig {
+        &self.config
+    }
+
+    async fn fetch(&self, client: &Client) -> Result<FetchedNews, OmniNewsError> {
+        let category = &self.config.category;
+
+        let rss = match fetch_text(client, &rss_url(&self.config.target)).await {
+            Ok(body) => parse_topic_rss(&body, category),
+            Err(e) => {
+                news_warn!("[Service] Failed to fetch google news rss: {}", e);
+                FetchedNews::from_candidates("rss", Vec::new())
+            }
+        };
+        if !rss.is_empty() {
+            return Ok(rss);
+        }
+
+        let res = fetch_text(client, &self.config.target).await?;
+        Ok(rss.or_else(|| parse_topic_page(&res, category)))
+    }
+}
+
+async fn fetch_text(client: &Client, url: &str) -> Result<String, OmniNewsError> {
+    Ok(client.get(url).send().await?.text().await?)
+}
+
+/// `https://news.google.com/topics/{id}?...` -> `https://news.google.com/rss/topics/{id}?...`
+fn rss_url(topic_url: &str) -> String {
+    if topic_url.contains("/rss/") {
+        return topic_url.to_string();
+    }
+    topic_url.replacen("news.google.com/", "news.google.com/rss/", 1)
+}
+
+/// 구글 뉴스 기사 링크(`/articles/...`, `/rss/articles/...`)인지 확인함.
+pub fn is_google_news_link(link: &str) -> bool {
+    Url::parse(link).ok().is_some_and(|url| {
+        url.host_str() == Some("news.google.com") && url.path().contains("/articles/")
+    })
+}
+
+/// 구글 뉴스 기사 링크를 언론사 원문 URL로 변환함. 찾지 못하면 `None`.
+/// 기사 ID에 원문 URL이 들어 있으면 바로 디코딩하고, 아니면 리다이렉트를 따라가거나
+/// 중간 페이지의 `data-n-au` 속성에서 원문 URL을 찾음.
+pub async fn resolve_google_news_link(client: &Client, link: &str) -> Option<String> {
+    if let Some(url) = decode_article_id(link) {
+        return Some(url);
+    }
+
+    let resp = match client.get(link).send().await {
+        Ok(resp) => resp,
+        Err(e) => {
+            news_warn!("[Service] Failed to resolve google news link: {}", e);
+            return None;
+        }
+    };
+    if resp.url().host_str() != Some("news.google.com") {
+        return Some(resp.url().to_string());
+    }
+
+    let body = resp.text().await.ok()?;
+    parse_publisher_link(&body)
+}
+
+// 예전 형식의 기사 ID는 원문 URL을 담은 protobuf를 base64로 인코딩한 값임
+fn decode_article_id(link: &str) -> Option<String> {
+    let url = Url::parse(link).ok()?;
+    let id = url.path().rsplit('/').next()?;
+    let bytes = URL_SAFE_NO_PAD.decode(id.trim_end_matches('=')).ok()?;
+
+    let start = bytes.windows(4).position(|window| window == b"http")?;
+    let end = bytes[start..]
+        .iter()
+        .position(|byte| !byte.is_ascii_graphic())
+        .map_or(bytes.len(), |offset| start + offset);
+
+    let decoded = String::from_utf8(bytes[start..end].to_vec()).ok()?;
+    Url::parse(&decoded).ok().map(|url| url.to_string())
+}
+
+fn parse_publisher_link(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse("[data-n-au]").unwrap();
+
+    document
+        .select(&selector)
+        .filter_map(|e| e.attr("data-n-au"))
+        .find(|url| url.starts_with("http"))
+        .map(str::to_string)
+}
+
+/// 구글 뉴스 RSS. 제목은 `기사 제목 - 언론사` 형식이므로 언론사를 분리함.
+/// RSS에는 썸네일이 없어 이미지 링크는 비워 둠.
+pub fn parse_topic_rss(body: &str, category: &str) -> FetchedNews {
+    let Ok(channel) = Channel::read_from(body.as_bytes()) else {
+        return FetchedNews::from_candidates("rss", Vec::new());
+    };
+
+    let candidates = channel
+        .items()
+        .iter()
+        .map(|item| {
+            let source = item
+                .source()
+                .and_then(|source| source.title())
+                .unwrap_or_default()
+                .to_string();
+            let title = item.title().map(|title| {
+                title
+                    .strip_suffix(&format!(" - {}", source))
+                    .unwrap_or(title)
+                    .trim()
+                    .to_string()
+            });
+
+            NewNews {
+                news_description: title.clone(),
+                news_title: title,
+                news_link: item.link().map(|link| link.to_string()),
+                news_source: Some(source),
+                news_pub_date: parse_pub_date(item.pub_date()),
+                news_image_link: Some(String::new()),
+                news_category: Some(category.to_string()),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    FetchedNews::from_candidates("rss", candidates)
+}
+
+/// 토픽 페이지는 JSON-LD -> CSS 클래스 순으로 파싱함.
+pub fn parse_topic_page(html: &str, category: &str) -> FetchedNews {
+    let document = Html::parse_document(html);
+
+    FetchedNews::from_candidates("json-ld", parse_json_ld_news(&document, category))
+        .or_else(|| FetchedNews::from_candidates("css", make_google_news(&document, category)))
+}
+
+fn make_google_news(document: &Html, subject: &str) -> Vec<NewNews> {
+    let news_selector = Selector::parse(".LU3Rqb").unwrap();
+    let title_selector = Selector::parse(".gPFEn").unwrap();
+    let description_selector = Selector::parse(".gPFEn").unwrap();
+    let link_selector = Selector::parse(".WwrzSb").unwrap();
+    let source_selector = Selector::parse(".vr1PYe").unwrap();
+    let pub_date_selector = Selector::parse(".IBr9hb > .UOVeFe > .hvbAAd").unwrap();
+    let image_link_selector = Selector::parse(".Quavad.vwBmvb").unwrap();
+
+    document
+        .select(&news_selector)
+        .map(|news| NewNews {
+            news_title: Some(
+                news.select(&title_selector)
+                    .next()
+                    .map(|e| e.inner_html())
+                    .unwrap_or_default(),
+            ),
+            news_description: Some(
+                news.select(&description_selector)
+                    .next()
+                    .map(|e| e.inner_html())
+                    .unwrap_or_default(),
+            ),
+            news_link: Some(
+                news.select(&link_selector)
+                    .next()
+                    .and_then(|e| e.attr("href"))
+                    .map(|value| format!("https://news.google.com/{}", value))
+                    .unwrap_or_default(),
+            ),
+            news_source: Some(
+                news.select(&source_selector)
+                    .next()
+                    .map(|e| e.inner_html())
+                    .unwrap_or_default(),
+            ),
+            // 표시 문자열("1시간 전")보다 time 요소의 datetime 속성이 정확함
+            news_pub_date: news.select(&pub_date_selector).next().and_then(|e| {
+                e.attr("datetime")
+                    .and_then(parse_news_date)
+                    .or_else(|| parse_news_date(&e.text().collect::<String>()))
+            }),
+            news_image_link: Some(
+                news.select(&image_link_selector)
+                    .next()
+                    .map(|e| e.attr("src").unwrap_or_default())
+                    .map(|value| format!("https://news.google.com{}", value))
+                    .unwrap_or_default(),
+            ),
+            news_category: Some(subject.to_string()),
+            ..Default::default()
+        })
+        .collect::<Vec<NewNews>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn converts_topic_url_to_rss_url() {
+        assert_eq!(
+            rss_url("https://news.google.com/topics/CAAqJggK?hl=ko&gl=KR&ceid=KR%3Ako"),
+            "https://news.google.com/rss/topics/CAAqJggK?hl=ko&gl=KR&ceid=KR%3Ako"
+        );
+        assert_eq!(
+            rss_url("https://news.google.com/rss/topics/CAAqJggK"),
+            "https://news.google.com/rss/topics/CAAqJggK"
+        );
+    }
+
+    #[test]
+    fn parses_topic_rss_fixture() {
+        let body = include_str!("../../../tests/fixtures/google_news_topic_rss.xml");
+        let fetched = parse_topic_rss(body, "주요");
+
+        assert_eq!(fetched.report.strategy, "rss");
+        assert_eq!(fetched.report.parsed, 2);
+
+        let first = &fetched.newsses[0];
+        // 제목 끝의 언론사 이름은 분리됨
+        assert_eq!(
+            first.news_title.as_deref(),
+            Some("정부, 반도체 지원 대책 발표")
+        );
+        assert_eq!(first.news_source.as_deref(), Some("한겨레"));
+        assert_eq!(
+            first.news_link.as_deref(),
+            Some("https://news.google.com/rss/articles/CBMiWkFVX3lxTFBxa3Bn?oc=5")
+        );
+        assert_eq!(
+            first.news_pub_date,
+            NaiveDate::from_ymd_opt(2025, 10, 15)
+                .unwrap()
+                .and_hms_opt(10, 12, 0)
+        );
+    }
+
+    #[test]
+    fn parses_topic_page_fixture_with_css_fallback() {
+        let html = include_str!("../../../tests/fixtures/google_news_topic.html");
+        let fetched = parse_topic_page(html, "주요");
+
+        assert_eq!(fetched.report.strategy, "css");
+        assert_eq!(fetched.report.parsed, 2);
+
+        let first = &fetched.newsses[0];
+        assert_eq!(
+            first.news_title.as_deref(),
+            Some("정부, 반도체 지원 대책 발표")
+        );
+        assert_eq!(first.news_source.as_deref(), Some("한겨레"));
+        assert!(first
+            .news_link
+            .as_deref()
+            .unwrap()
+            .starts_with("https://news.google.com/"));
+        assert!(first.news_pub_date.is_some());
+    }
+
+    #[test]
+    fn decodes_publisher_url_from_article_id() {
+        let url = "https://www.hani.co.kr/arti/economy/1.html";
+        let mut bytes = vec![0x08, 0x13, 0x22, url.len() as u8];
+        bytes.extend_from_slice(url.as_bytes());
+        bytes.extend_from_slice(&[0xd2, 0x01, 0x00]);
+        let link = format!(
+            "https://news.google.com/rss/articles/{}?oc=5",
+            URL_SAFE_NO_PAD.encode(&bytes)
+        );
+
+        assert!(is_google_news_link(&link));
+        assert_eq!(decode_article_id(&link).as_deref(), Some(url));
+    }
+
+    #[test]
+    fn leaves_opaque_article_id_for_redirect_resolution() {
+        // 새 형식의 ID에는 원문 URL이 없어 중간 페이지를 조회해야 함
+        let link = "https://news.google.com/rss/articles/CBMiWkFVX3lxTFBxa3Bn?oc=5";
+        assert!(decode_article_id(link).is_none());
+
+        let html = r#"<html><body><c-wiz><div jscontroller="x" data-n-au="https://www.hani.co.kr/arti/1.html"></div></c-wiz></body></html>"#;
+        assert_eq!(
+            parse_publisher_link(html).as_deref(),
+            Some("https://www.hani.co.kr/arti/1.html")
+        );
+        assert!(!is_google_news_link(
+            "https://news.google.com/topics/CAAqJggK"
+        ));
+        assert!(!is_google_news_link("https://www.hani.co.kr/arti/1.html"));
+    }
+
+    #[test]
+    fn returns_empty_rss_on_invalid_body() {
+        let fetched = parse_topic_rss("<html>not rss</html>", "주요");
+        assert!(fetched.is_empty());
+    }
+}
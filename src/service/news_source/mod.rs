@@ -0,0 +1,296 @@
+pub mod article_extractor;
+pub mod daum;
+pub mod google;
+pub mod naver;
+pub mod publisher_rss;
+pub mod structured;
+pub mod yield_monitor;
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use crate::{
+    config::env::load_json_config,
+    model::{error::OmniNewsError, news::NewNews},
+    news_info, news_warn,
+};
+
+use self::{
+    daum::DaumNewsSource, google::GoogleNewsSource, naver::NaverSectionSource,
+    publisher_rss::PublisherRssSource, yield_monitor::YieldMonitor,
+};
+
+const DEFAULT_NEWS_SOURCES_PATH: &str = "news_sources.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NewsSourceKind {
+    Naver,
+    Google,
+    Daum,
+    Rss,
+}
+
+/// `news_sources.json`의 소스 하나. 소스나 카테고리를 추가할 때는 이 파일만 수정하면 됨.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewsSourceConfig {
+    pub name: String,
+    pub kind: NewsSourceKind,
+    // 저장될 news_category
+    pub category: String,
+    // naver: 섹션 코드, google: 토픽 URL, daum: 카테고리 경로, rss: 피드 URL
+    pub target: String,
+    #[serde(default = "default_interval_minutes")]
+    pub interval_minutes: u64,
+    // 목록 상단의 헤드라인 기사 수. 수집하지 않고 건너뜀.
+    #[serde(default)]
+    pub skip_headlines: usize,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_interval_minutes() -> u64 {
+    5
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 한 번의 수집에서 어떤 방식으로 몇 개를 파싱했는지. 파싱 실패는 패닉 대신 개수로 보고함.
+#[derive(Debug, Clone, Default)]
+pub struct ParseReport {
+    // 최종적으로 사용한 방식 (json-ld, rss, css)
+    pub strategy: &'static str,
+    pub parsed: usize,
+    // 제목이나 링크가 없어 버린 아이템 수
+    pub skipped: usize,
+    // 결과가 없어 다음 방식으로 넘어간 방식들
+    pub fallbacks: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FetchedNews {
+    pub newsses: Vec<NewNews>,
+    pub report: ParseReport,
+}
+
+impl FetchedNews {
+    /// 제목과 링크가 있는 아이템만 남기고 나머지는 `skipped`로 셈.
+    pub fn from_candidates(strategy: &'static str, candidates: Vec<NewNews>) -> Self {
+        let total = candidates.len();
+        let newsses = candidates
+            .into_iter()
+            .filter(|news| {
+                let has_title = news.news_title.as_deref().is_some_and(|t| !t.is_empty());
+                let has_link = news.news_link.as_deref().is_some_and(|l| !l.is_empty());
+                has_title && has_link
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            report: ParseReport {
+                strategy,
+                parsed: newsses.len(),
+                skipped: total - newsses.len(),
+                fallbacks: Vec::new(),
+            },
+            newsses,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.newsses.is_empty()
+    }
+
+    /// 목록 페이지(css)에서 파싱한 경우에만 상단 헤드라인 `skip_headlines`개를 건너뜀.
+    /// RSS, JSON-LD 결과에는 헤드라인 영역이 없으므로 모두 사용함.
+    pub fn into_newsses(self, skip_headlines: usize) -> Vec<NewNews> {
+        let skip = if self.report.strategy == "css" {
+            skip_headlines
+        } else {
+            0
+        };
+        self.newsses.into_iter().skip(skip).collect()
+    }
+
+    /// 결과가 비어 있으면 `fallback`을 사용하고, 시도한 방식을 기록함.
+    pub fn or_else(self, fallback: impl FnOnce() -> FetchedNews) -> FetchedNews {
+        if !self.is_empty() {
+            return self;
+        }
+        let mut next = fallback();
+        next.report.fallbacks = self.report.fallbacks;
+        next.report.fallbacks.push(self.report.strategy);
+        next.report.skipped += self.report.skipped;
+        next
+    }
+}
+
+#[async_trait]
+pub trait NewsSource: Send + Sync {
+    fn config(&self) -> &NewsSourceConfig;
+
+    /// 소스에서 뉴스 목록을 가져옴. 헤드라인 건너뛰기는 호출부에서 `FetchedNews::into_newsses`로 처리함.
+    async fn fetch(&self, client: &Client) -> Result<FetchedNews, OmniNewsError>;
+}
+
+fn build_source(config: NewsSourceConfig) -> Box<dyn NewsSource> {
+    match config.kind {
+        NewsSourceKind::Naver => Box::new(NaverSectionSource::new(config)),
+        NewsSourceKind::Google => Box::new(GoogleNewsSource::new(config)),
+        NewsSourceKind::Daum => Box::new(DaumNewsSource::new(config)),
+        NewsSourceKind::Rss => Box::new(PublisherRssSource::new(config)),
+    }
+}
+
+/// 설정된 뉴스 소스 목록과 소스별 마지막 수집 시각.
+pub struct NewsSourceRegistry {
+    sources: Vec<Box<dyn NewsSource>>,
+    last_fetched: Mutex<HashMap<String, Instant>>,
+    yield_monitor: YieldMonitor,
+}
+
+impl NewsSourceRegistry {
+    /// `NEWS_SOURCES_PATH`(기본값: `news_sources.json`)에서 소스 설정을 읽음.
+    /// 파일이 없거나 잘못되면 기본 네이버 섹션 + 구글 뉴스 설정을 사용함.
+    pub fn from_config() -> Self {
+        let configs = load_json_config::<Vec<NewsSourceConfig>>(
+            "NEWS_SOURCES_PATH",
+            DEFAULT_NEWS_SOURCES_PATH,
+        )
+        .unwrap_or_else(|e| {
+            news_warn!("[Service] Failed to load news sources, use defaults: {}", e);
+            default_configs()
+        });
+
+        let sources = configs
+            .into_iter()
+            .filter(|config| config.enabled)
+            .map(build_source)
+            .collect::<Vec<_>>();
+
+        news_info!(
+            "[Service] News sources: {}",
+            sources
+                .iter()
+                .map(|s| s.config().name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        Self {
+            sources,
+            last_fetched: Mutex::new(HashMap::new()),
+            yield_monitor: YieldMonitor::from_env(),
+        }
+    }
+
+    pub fn yield_monitor(&self) -> &YieldMonitor {
+        &self.yield_monitor
+    }
+
+    /// 수집 주기가 돌아온 소스들을 반환함. 수집 시각은 `mark_fetched`에서 갱신함.
+    pub fn due_sources(&self) -> Vec<&dyn NewsSource> {
+        let now = Instant::now();
+        let last_fetched = self.last_fetched.lock().unwrap();
+
+        self.sources
+            .iter()
+            .filter(|source| {
+                let config = source.config();
+                let interval = Duration::from_secs(config.interval_minutes * 60);
+                last_fetched
+                    .get(&config.name)
+                    .map(|last| now.duration_since(*last) >= interval)
+                    .unwrap_or(true)
+            })
+            .map(|source| source.as_ref())
+            .collect()
+    }
+
+    /// 수집에 성공한 소스의 수집 시각을 기록함. 실패한 소스는 다음 주기 확인 때 다시 수집함.
+    pub fn mark_fetched(&self, name: &str) {
+        self.last_fetched
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), Instant::now());
+    }
+}
+
+/*
+* 정치 : 100
+* 경제 : 101
+* 사회 : 102
+* 생활/문화 : 103
+* 세계 : 104
+* IT/과학 : 105
+*/
+/// 설정 파일이 없을 때 사용하는 기존 수집 대상.
+fn default_configs() -> Vec<NewsSourceConfig> {
+    let naver = [
+        ("정치", "100"),
+        ("경제", "101"),
+        ("사회", "102"),
+        ("생활/문화", "103"),
+        ("세계", "104"),
+        ("IT/과학", "105"),
+    ]
+    .into_iter()
+    .map(|(category, code)| NewsSourceConfig {
+        name: format!("naver-{}", code),
+        kind: NewsSourceKind::Naver,
+        category: category.to_string(),
+        target: code.to_string(),
+        interval_minutes: default_interval_minutes(),
+        skip_headlines: 9,
+        enabled: true,
+    });
+
+    // 주요뉴스는 구글 뉴스 토픽 사용
+    let google = NewsSourceConfig {
+        name: "google-headlines".to_string(),
+        kind: NewsSourceKind::Google,
+        category: "주요".to_string(),
+        target: "https://news.google.com/topics/CAAqJggKIiBDQkFTRWdvSUwyMHZNRFZxYUdjU0FtdHZHZ0pMVWlnQVAB?hl=ko&gl=KR&ceid=KR%3Ako".to_string(),
+        interval_minutes: default_interval_minutes(),
+        skip_headlines: 9,
+        enabled: true,
+    };
+
+    naver.chain(std::iter::once(google)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(count: usize) -> Vec<NewNews> {
+        (0..count)
+            .map(|i| NewNews {
+                news_title: Some(format!("title {i}")),
+                news_link: Some(format!("https://example.com/{i}")),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn skips_headlines_only_for_css_results() {
+        let css = FetchedNews::from_candidates("css", candidates(5)).into_newsses(2);
+        assert_eq!(css.len(), 3);
+        assert_eq!(css[0].news_title.as_deref(), Some("title 2"));
+
+        for strategy in ["rss", "json-ld"] {
+            let newsses = FetchedNews::from_candidates(strategy, candidates(5)).into_newsses(2);
+            assert_eq!(newsses.len(), 5, "{strategy}");
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use reqwest::Client;
+use scraper::{Html, Selector};
+
+use crate::{
+    model::{error::OmniNewsError, news::NewNews},
+    utils::date_util::parse_news_date,
+};
+
+use super::{structured::parse_json_ld_news, FetchedNews, NewsSource, NewsSourceConfig};
+
+/// 네이버 뉴스 섹션 (`https://news.naver.com/section/{code}`).
+pub struct NaverSectionSource {
+    config: NewsSourceConfig,
+}
+
+impl NaverSectionSource {
+    pub fn new(config: NewsSourceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl NewsSource for NaverSectionSource {
+    fn config(&self) -> &NewsSourceConfig {
+        &self.config
+    }
+
+    async fn fetch(&self, client: &Client) -> Result<FetchedNews, OmniNewsError> {
+        let site = format!("https://news.naver.com/section/{}", self.config.target);
+        let res = client.get(site).send().await?.text().await?;
+
+        Ok(parse_section(&res, &self.config.category))
+    }
+}
+
+/// JSON-LD를 우선 사용하고, 없으면 CSS 클래스로 파싱함.
+/// CSS 클래스는 사이트 배포 때마다 바뀔 수 있으므로 마지막 수단으로만 사용함.
+pub fn parse_section(html: &str, category: &str) -> FetchedNews {
+    let document = Html::parse_document(html);
+
+    FetchedNews::from_candidates("json-ld", parse_json_ld_news(&document, category))
+        .or_else(|| FetchedNews::from_candidates("css", make_news(&document, category)))
+}
+
+fn make_news(document: &Html, subject: &str) -> Vec<NewNews> {
+    let news_selector = Selector::parse(".sa_item_flex").unwrap();
+    let title_selector = Selector::parse(".sa_text_strong").unwrap();
+    let description_selector = Selector::parse(".sa_text_lede").unwrap();
+    let link_selector = Selector::parse(".sa_thumb_link").unwrap();
+    let source_selector = Selector::parse(".sa_text_press").unwrap();
+    let pub_date_selector = Selector::parse(".sa_text_datetime > b").unwrap();
+    let image_link_selector = Selector::parse(".sa_thumb_link > img").unwrap();
+
+    document
+        .select(&news_selector)
+        .map(|news| NewNews {
+            news_title: Some(
+                news.select(&title_selector)
+                    .next()
+                    .map(|e| e.inner_html())
+                    .unwrap_or_default(),
+            ),
+            news_description: Some(
+                news.select(&description_selector)
+                    .next()
+                    .map(|e| e.inner_html())
+                    .unwrap_or_default(),
+            ),
+            news_link: Some(
+                news.select(&link_selector)
+                    .next()
+                    .and_then(|e| e.attr("href"))
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            news_source: Some(
+                news.select(&source_selector)
+                    .next()
+                    .map(|e| e.inner_html())
+                    .unwrap_or_default(),
+            ),
+            news_pub_date: news
+                .select(&pub_date_selector)
+                .next()
+                .and_then(|e| parse_news_date(&e.text().collect::<String>())),
+            news_image_link: Some(
+                news.select(&image_link_selector)
+                    .next()
+                    .map(|e| e.attr("data-src").unwrap_or_default())
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            news_category: Some(subject.to_string()),
+            ..Default::default()
+        })
+        .collect::<Vec<NewNews>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_section_fixture_with_css_fallback() {
+        let html = include_str!("../../../tests/fixtures/naver_section.html");
+        let fetched = parse_section(html, "정치");
+
+        assert_eq!(fetched.report.strategy, "css");
+        assert_eq!(fetched.report.fallbacks, vec!["json-ld"]);
+        assert_eq!(fetched.report.parsed, 2);
+        // 링크가 없는 아이템은 패닉 없이 제외됨
+        assert_eq!(fetched.report.skipped, 1);
+
+        let first = &fetched.newsses[0];
+        assert_eq!(
+            first.news_title.as_deref(),
+            Some("국회, 내년도 예산안 심사 착수")
+        );
+        assert_eq!(
+            first.news_link.as_deref(),
+            Some("https://n.news.naver.com/mnews/article/001/0015000001")
+        );
+        assert_eq!(first.news_source.as_deref(), Some("연합뉴스"));
+        assert_eq!(first.news_category.as_deref(), Some("정치"));
+        assert!(first.news_pub_date.is_some());
+        assert_eq!(
+            first.news_image_link.as_deref(),
+            Some("https://imgnews.pstatic.net/image/001/2025/10/15/0015000001_001.jpg")
+        );
+    }
+
+    #[test]
+    fn prefers_json_ld_over_css() {
+        let html = include_str!("../../../tests/fixtures/json_ld_item_list.html");
+        let fetched = parse_section(html, "경제");
+
+        assert_eq!(fetched.report.strategy, "json-ld");
+        assert!(fetched.report.fallbacks.is_empty());
+        assert_eq!(fetched.report.parsed, 2);
+    }
+
+    #[test]
+    fn reports_zero_items_on_unknown_markup() {
+        let fetched = parse_section(
+            "<html><body><div class=\"new_layout\"></div></body></html>",
+            "정치",
+        );
+
+        assert_eq!(fetched.report.parsed, 0);
+        assert_eq!(fetched.report.skipped, 0);
+    }
+}
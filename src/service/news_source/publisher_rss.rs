@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use reqwest::Client;
+use rss::Channel;
+use scraper::Html;
+
+use crate::{
+    model::{error::OmniNewsError, news::NewNews},
+    news_warn,
+    service::rss::item_service::parse_pub_date,
+};
+
+use super::{FetchedNews, NewsSource, NewsSourceConfig};
+
+/// 언론사가 제공하는 RSS 피드. `target`에 피드 URL을 설정함.
+pub struct PublisherRssSource {
+    config: NewsSourceConfig,
+}
+
+impl PublisherRssSource {
+    pub fn new(config: NewsSourceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl NewsSource for PublisherRssSource {
+    fn config(&self) -> &NewsSourceConfig {
+        &self.config
+    }
+
+    async fn fetch(&self, client: &Client) -> Result<FetchedNews, OmniNewsError> {
+        let body = client
+            .get(&self.config.target)
+            .send()
+            .await?
+            .bytes()
+            .await?;
+
+        let channel = Channel::read_from(&body[..]).map_err(|e| {
+            news_warn!(
+                "[Service] Failed to parse rss feed {}: {}",
+                self.config.target,
+                e
+            );
+            OmniNewsError::ParseRssChannel
+        })?;
+
+        let candidates = channel
+            .items()
+            .iter()
+            .map(|item| NewNews {
+                news_title: item.title().map(|title| title.trim().to_string()),
+                news_description: Some(strip_html(item.description().unwrap_or_default())),
+                news_link: item.link().map(|link| link.to_string()),
+                news_source: Some(channel.title().to_string()),
+                news_pub_date: parse_pub_date(item.pub_date()),
+                news_image_link: Some(
+                    item.enclosure()
+                        .filter(|enclosure| enclosure.mime_type().starts_with("image"))
+                        .map(|enclosure| enclosure.url().to_string())
+                        .unwrap_or_default(),
+                ),
+                news_category: Some(self.config.category.clone()),
+                ..Default::default()
+            })
+            .collect();
+
+        // 제목이나 링크가 없는 아이템은 중복 확인이 불가능하므로 제외
+        Ok(FetchedNews::from_candidates("rss", candidates))
+    }
+}
+
+fn strip_html(html: &str) -> String {
+    Html::parse_fragment(html)
+        .root_element()
+        .text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
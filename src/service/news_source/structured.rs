@@ -0,0 +1,182 @@
+use chrono::NaiveDateTime;
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+use crate::{model::news::NewNews, utils::date_util::parse_news_date};
+
+// JSON-LD에서 기사로 취급하는 타입
+const ARTICLE_TYPES: &[&str] = &[
+    "NewsArticle",
+    "Article",
+    "ReportageNewsArticle",
+    "AnalysisNewsArticle",
+    "BlogPosting",
+];
+
+/// 페이지의 `<script type="application/ld+json">`에서 기사 목록을 추출함.
+/// `ItemList`의 항목과 `NewsArticle` 계열 노드를 모두 사용하며, 잘못된 JSON 블록은 건너뜀.
+pub fn parse_json_ld_news(document: &Html, category: &str) -> Vec<NewNews> {
+    let script_selector = Selector::parse(r#"script[type="application/ld+json"]"#).unwrap();
+
+    let mut newsses = Vec::new();
+    for script in document.select(&script_selector) {
+        let Ok(value) = serde_json::from_str::<Value>(&script.text().collect::<String>()) else {
+            continue;
+        };
+
+        for node in flatten_nodes(&value) {
+            if has_type(node, "ItemList") {
+                let elements = node
+                    .get("itemListElement")
+                    .and_then(Value::as_array)
+                    .cloned()
+                    .unwrap_or_default();
+                for element in &elements {
+                    // ListItem은 실제 기사를 `item`에 담거나 url/name만 가짐
+                    let item = element.get("item").unwrap_or(element);
+                    newsses.push(json_ld_to_news(item, category));
+                }
+            } else if ARTICLE_TYPES.iter().any(|t| has_type(node, t)) {
+                newsses.push(json_ld_to_news(node, category));
+            }
+        }
+    }
+    newsses
+}
+
+/// 기사 페이지의 OpenGraph 메타 정보.
+#[derive(Debug, Clone, Default)]
+pub struct OpenGraph {
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub published_time: Option<NaiveDateTime>,
+}
+
+pub fn parse_open_graph(document: &Html) -> OpenGraph {
+    let meta = |property: &str| {
+        let selector = Selector::parse(&format!(
+            r#"meta[property="{0}"], meta[name="{0}"]"#,
+            property
+        ))
+        .unwrap();
+        document
+            .select(&selector)
+            .next()
+            .and_then(|e| e.attr("content"))
+            .map(|content| content.trim().to_string())
+            .filter(|content| !content.is_empty())
+    };
+
+    OpenGraph {
+        description: meta("og:description"),
+        image: meta("og:image"),
+        published_time: meta("article:published_time").and_then(|date| parse_news_date(&date)),
+    }
+}
+
+fn flatten_nodes(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().flat_map(flatten_nodes).collect(),
+        Value::Object(object) => match object.get("@graph") {
+            Some(graph) => flatten_nodes(graph),
+            None => vec![value],
+        },
+        _ => Vec::new(),
+    }
+}
+
+fn has_type(node: &Value, expected: &str) -> bool {
+    match node.get("@type") {
+        Some(Value::String(t)) => t == expected,
+        Some(Value::Array(types)) => types.iter().any(|t| t.as_str() == Some(expected)),
+        _ => false,
+    }
+}
+
+fn json_ld_to_news(node: &Value, category: &str) -> NewNews {
+    let text = |key: &str| {
+        node.get(key)
+            .and_then(Value::as_str)
+            .map(|s| html_escape::decode_html_entities(s.trim()).to_string())
+            .filter(|s| !s.is_empty())
+    };
+
+    let link = text("url").or_else(|| match node.get("mainEntityOfPage") {
+        Some(Value::String(url)) => Some(url.to_string()),
+        Some(page) => page.get("@id").and_then(Value::as_str).map(str::to_string),
+        None => None,
+    });
+
+    NewNews {
+        news_title: text("headline").or_else(|| text("name")),
+        news_description: Some(text("description").unwrap_or_default()),
+        news_link: link,
+        news_source: Some(
+            node.get("publisher")
+                .and_then(|publisher| publisher.get("name"))
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+        ),
+        news_pub_date: text("datePublished").and_then(|date| parse_news_date(&date)),
+        news_image_link: Some(node.get("image").and_then(image_url).unwrap_or_default()),
+        news_category: Some(category.to_string()),
+        ..Default::default()
+    }
+}
+
+// image는 문자열, ImageObject, 또는 그 배열일 수 있음
+fn image_url(value: &Value) -> Option<String> {
+    match value {
+        Value::String(url) => Some(url.to_string()),
+        Value::Array(values) => values.iter().find_map(image_url),
+        Value::Object(object) => object
+            .get("url")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn parses_json_ld_item_list_fixture() {
+        let html = include_str!("../../../tests/fixtures/json_ld_item_list.html");
+        let newsses = parse_json_ld_news(&Html::parse_document(html), "경제");
+
+        // 잘못된 JSON 블록과 WebPage 노드는 무시하고 ItemList 항목만 사용함
+        assert_eq!(newsses.len(), 3);
+
+        let first = &newsses[0];
+        assert_eq!(first.news_title.as_deref(), Some("한국은행, 기준금리 동결"));
+        assert_eq!(
+            first.news_link.as_deref(),
+            Some("https://news.example.com/articles/1")
+        );
+        assert_eq!(first.news_source.as_deref(), Some("예시일보"));
+        assert_eq!(
+            first.news_image_link.as_deref(),
+            Some("https://img.example.com/1.jpg")
+        );
+        assert_eq!(
+            first.news_pub_date,
+            NaiveDate::from_ymd_opt(2025, 10, 15)
+                .unwrap()
+                .and_hms_opt(10, 30, 0)
+        );
+
+        let second = &newsses[1];
+        assert_eq!(
+            second.news_title.as_deref(),
+            Some("코스피, 외국인 매수에 상승 마감")
+        );
+        assert!(second.news_pub_date.is_none());
+
+        assert!(newsses[2].news_link.is_none());
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    env,
+    sync::Mutex,
+};
+
+use reqwest::Client;
+use serde_json::json;
+
+use crate::{config::env::get_env_or, news_error, news_info, news_warn};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum YieldStatus {
+    Normal,
+    // 이번 수집에서 기준치보다 급격히 줄어든 경우. 이상 상태가 이어지는 동안은 한 번만 반환함.
+    Anomaly { parsed: usize, baseline: f64 },
+    Recovered { parsed: usize, baseline: f64 },
+}
+
+#[derive(Default)]
+struct SourceYield {
+    counts: VecDeque<usize>,
+    alerting: bool,
+}
+
+/// 소스별 수집 개수를 최근 `window`번의 평균과 비교해, 마크업 변경 등으로
+/// 파싱 개수가 급감한 소스를 찾아냄.
+pub struct YieldMonitor {
+    window: usize,
+    drop_ratio: f64,
+    min_runs: usize,
+    history: Mutex<HashMap<String, SourceYield>>,
+}
+
+impl YieldMonitor {
+    pub fn new(window: usize, drop_ratio: f64, min_runs: usize) -> Self {
+        Self {
+            window: window.max(1),
+            drop_ratio,
+            min_runs,
+            history: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            get_env_or("NEWS_YIELD_WINDOW", 12_usize),
+            get_env_or("NEWS_YIELD_DROP_RATIO", 0.3),
+            get_env_or("NEWS_YIELD_MIN_RUNS", 3_usize),
+        )
+    }
+
+    pub fn record(&self, source: &str, parsed: usize) -> YieldStatus {
+        let mut history = self.history.lock().unwrap();
+        let entry = history.entry(source.to_string()).or_default();
+
+        let baseline = if entry.counts.is_empty() {
+            0.0
+        } else {
+            entry.counts.iter().sum::<usize>() as f64 / entry.counts.len() as f64
+        };
+        let is_drop = entry.counts.len() >= self.min_runs
+            && baseline > 0.0
+            && (parsed as f64) < baseline * self.drop_ratio;
+
+        if is_drop {
+            // 이상치는 기준치에 반영하지 않음
+            if entry.alerting {
+                return YieldStatus::Normal;
+            }
+            entry.alerting = true;
+            return YieldStatus::Anomaly { parsed, baseline };
+        }
+
+        entry.counts.push_back(parsed);
+        if entry.counts.len() > self.window {
+            entry.counts.pop_front();
+        }
+
+        if entry.alerting {
+            entry.alerting = false;
+            return YieldStatus::Recovered { parsed, baseline };
+        }
+        YieldStatus::Normal
+    }
+}
+
+/// 이상/복구 상태를 로그로 남기고, `NEWS_YIELD_ALERT_WEBHOOK_URL`이 설정되어 있으면
+/// `{"text": ...}` 형식(Slack 호환)으로 웹훅을 보냄.
+pub async fn notify_yield_status(client: &Client, source: &str, status: &YieldStatus) {
+    let message = match status {
+        YieldStatus::Normal => return,
+        YieldStatus::Anomaly { parsed, baseline } => {
+            let message = format!(
+                "[OmniNews] 뉴스 소스 {} 수집량 급감: {}개 (최근 평균 {:.1}개). 마크업 변경 여부를 확인하세요.",
+                source, parsed, baseline
+            );
+            news_error!("[Service] {}", message);
+            message
+        }
+        YieldStatus::Recovered { parsed, baseline } => {
+            let message = format!(
+                "[OmniNews] 뉴스 소스 {} 수집량 복구: {}개 (최근 평균 {:.1}개)",
+                source, parsed, baseline
+            );
+            news_info!("[Service] {}", message);
+            message
+        }
+    };
+
+    let Ok(webhook_url) = env::var("NEWS_YIELD_ALERT_WEBHOOK_URL") else {
+        return;
+    };
+    if webhook_url.is_empty() {
+        return;
+    }
+
+    match client
+        .post(&webhook_url)
+        .json(&json!({ "text": message }))
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => (),
+        Ok(resp) => news_warn!("[Service] Yield alert webhook returned {}", resp.status()),
+        Err(e) => news_warn!("[Service] Failed to send yield alert: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_sharp_drop_once_and_recovers() {
+        let monitor = YieldMonitor::new(5, 0.3, 3);
+        for _ in 0..3 {
+            assert_eq!(monitor.record("naver-100", 20), YieldStatus::Normal);
+        }
+
+        assert_eq!(
+            monitor.record("naver-100", 0),
+            YieldStatus::Anomaly {
+                parsed: 0,
+                baseline: 20.0
+            }
+        );
+        // 이상 상태가 이어지는 동안 다시 알리지 않음
+        assert_eq!(monitor.record("naver-100", 1), YieldStatus::Normal);
+
+        assert_eq!(
+            monitor.record("naver-100", 18),
+            YieldStatus::Recovered {
+                parsed: 18,
+                baseline: 20.0
+            }
+        );
+        assert_eq!(monitor.record("naver-100", 19), YieldStatus::Normal);
+    }
+
+    #[test]
+    fn waits_for_baseline_before_flagging() {
+        let monitor = YieldMonitor::new(5, 0.3, 3);
+        assert_eq!(monitor.record("daum-politics", 20), YieldStatus::Normal);
+        assert_eq!(monitor.record("daum-politics", 0), YieldStatus::Normal);
+    }
+
+    #[test]
+    fn tracks_sources_independently() {
+        let monitor = YieldMonitor::new(5, 0.3, 1);
+        monitor.record("naver-100", 20);
+        monitor.record("google-headlines", 2);
+
+        assert_eq!(monitor.record("google-headlines", 2), YieldStatus::Normal);
+        assert!(matches!(
+            monitor.record("naver-100", 2),
+            YieldStatus::Anomaly { .. }
+        ));
+    }
+}
@@ -0,0 +1,458 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Duration, FixedOffset, NaiveDateTime, Utc};
+use sqlx::MySqlPool;
+
+use crate::{
+    config::env::get_env_or,
+    model::{
+        error::OmniNewsError,
+        recommendation::{NewRecommendation, RecommendationCandidate, RecommendationUser},
+        rss::NewticleType,
+    },
+    recommendation_error, recommendation_info, recommendation_warn,
+    repository::{embedding_repository, recommendation_repository, search_repository},
+    service::user_service,
+    utils::{
+        embedding_util::decode_embedding, firebase::send_fcm::send_fcm_message,
+        index_searcher::IndexSearcher,
+    },
+};
+
+const DIGEST_TITLE: &str = "오늘의 추천";
+// 다이제스트 알림 본문에 넣을 제목 수
+const DIGEST_ITEMS: usize = 3;
+
+struct RecommendationConfig {
+    limit: usize,
+    candidates: usize,
+    max_age_hours: i64,
+    read_days: i64,
+    read_weight: f32,
+    digest: bool,
+}
+
+impl RecommendationConfig {
+    fn from_env() -> Self {
+        Self {
+            limit: get_env_or("RECOMMENDATION_LIMIT", 20_usize).max(1),
+            candidates: get_env_or("RECOMMENDATION_CANDIDATES", 200_usize).max(1),
+            max_age_hours: get_env_or("RECOMMENDATION_MAX_AGE_HOURS", 48_i64).max(1),
+            read_days: get_env_or("RECOMMENDATION_READ_DAYS", 14_i64).max(1),
+            read_weight: get_env_or("RECOMMENDATION_READ_WEIGHT", 2.0_f32).max(0.0),
+            digest: get_env_or("RECOMMENDATION_DIGEST_PUSH", false),
+        }
+    }
+}
+
+/// 구독 채널과 최근 읽은 항목의 임베딩으로 사용자별 관심사 벡터를 만들고, RSS/뉴스 인덱스에서
+/// 구독하지 않은 채널의 최근 항목 중 읽지 않은 것을 골라 추천 목록을 교체함.
+/// `RECOMMENDATION_DIGEST_PUSH`가 켜져 있으면 추천 상위 항목으로 다이제스트 알림을 보냄.
+/// 추천 목록을 만든 사용자 수를 반환함.
+pub async fn create_recommendations(
+    pool: &MySqlPool,
+    searcher: &IndexSearcher,
+) -> Result<usize, OmniNewsError> {
+    let model_version = embedding_repository::select_active_embedding_model(pool)
+        .await
+        .map_err(OmniNewsError::Database)?
+        .and_then(|model| model.embedding_model_version);
+    let Some(model_version) = model_version else {
+        recommendation_warn!("[Service] No active embedding model, skip recommendations.");
+        return Ok(0);
+    };
+
+    let config = RecommendationConfig::from_env();
+    let users = recommendation_repository::select_recommendation_users(pool, config.read_days)
+        .await
+        .map_err(|e| {
+            recommendation_error!("[Service] Failed to select recommendation users: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    // 발행 시각은 KST로 저장되므로 같은 기준으로 비교함
+    let since = Utc::now()
+        .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+        .naive_local()
+        - Duration::hours(config.max_age_hours);
+    // 같은 후보가 여러 사용자에게 나오므로 한 번 조회한 항목은 재사용함
+    let mut cache = HashMap::new();
+    let mut created = 0;
+    let mut digests = 0;
+
+    for user in &users {
+        let recommendations = match recommend_for_user(
+            pool,
+            searcher,
+            &config,
+            model_version,
+            user.user_id,
+            since,
+            &mut cache,
+        )
+        .await
+        {
+            Ok(recommendations) => recommendations,
+            // 인덱스가 없거나 모델 전환 중이면 모든 사용자가 같으므로 다음 실행을 기다림
+            Err(OmniNewsError::NotFound(target)) => {
+                recommendation_warn!("[Service] Skip recommendations, not found: {}", target);
+                break;
+            }
+            Err(e) => {
+                recommendation_warn!(
+                    "[Service] Failed to recommend for user {}: {:?}",
+                    user.user_id,
+                    e
+                );
+                continue;
+            }
+        };
+
+        let rows = recommendations
+            .iter()
+            .enumerate()
+            .map(|(rank, candidate)| NewRecommendation {
+                recommendation_rank: rank as i32 + 1,
+                rss_id: candidate.rss_id,
+                news_id: candidate.news_id,
+                recommendation_score: candidate.score as f64,
+            })
+            .collect::<Vec<_>>();
+        if let Err(e) =
+            recommendation_repository::replace_user_recommendations(pool, user.user_id, &rows).await
+        {
+            recommendation_error!(
+                "[Service] Failed to store recommendations for user {}: {:?}",
+                user.user_id,
+                e
+            );
+            continue;
+        }
+        created += 1;
+
+        if config.digest && !recommendations.is_empty() {
+            match send_digest(pool, user, &recommendations).await {
+                Ok(true) => digests += 1,
+                Ok(false) => (),
+                Err(e) => recommendation_warn!(
+                    "[Service] Failed to send digest to user {}: {:?}",
+                    user.user_id,
+                    e
+                ),
+            }
+        }
+    }
+
+    recommendation_info!(
+        "[Service] Created recommendations for {}/{} users, sent {} digests",
+        created,
+        users.len(),
+        digests
+    );
+    Ok(created)
+}
+
+async fn recommend_for_user(
+    pool: &MySqlPool,
+    searcher: &IndexSearcher,
+    config: &RecommendationConfig,
+    model_version: i32,
+    user_id: i32,
+    since: NaiveDateTime,
+    cache: &mut HashMap<i32, Option<RecommendationCandidate>>,
+) -> Result<Vec<RecommendationCandidate>, OmniNewsError> {
+    let subscriptions = recommendation_repository::select_subscribed_channel_embeddings(
+        pool,
+        user_id,
+        model_version,
+    )
+    .await
+    .map_err(OmniNewsError::Database)?;
+    let reads = recommendation_repository::select_read_embeddings(
+        pool,
+        user_id,
+        model_version,
+        config.read_days,
+    )
+    .await
+    .map_err(OmniNewsError::Database)?;
+
+    let channel_vectors = subscriptions
+        .iter()
+        .filter_map(|s| s.embedding_value.as_deref().map(decode_embedding))
+        .collect::<Vec<_>>();
+    let read_vectors = reads
+        .iter()
+        .filter_map(|r| r.embedding_value.as_deref().map(decode_embedding))
+        .collect::<Vec<_>>();
+    let Some(interest) = interest_vector(&channel_vectors, &read_vectors, config.read_weight)
+    else {
+        return Ok(Vec::new());
+    };
+
+    let mut candidates = Vec::new();
+    for category in [NewticleType::Rss, NewticleType::News] {
+        let hits = searcher
+            .search(
+                &category,
+                model_version,
+                interest.clone(),
+                config.candidates,
+            )
+            .await?;
+        // 다른 사용자 때문에 이미 조회한 임베딩은 빼고 한 번에 조회함
+        let missing = hits
+            .iter()
+            .map(|(embedding_id, _)| *embedding_id)
+            .filter(|embedding_id| !cache.contains_key(embedding_id))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            let mut found = find_candidates(pool, &category, &missing, since).await?;
+            for embedding_id in missing {
+                cache.insert(embedding_id, found.remove(&embedding_id));
+            }
+        }
+
+        for (embedding_id, score) in hits {
+            if let Some(Some(candidate)) = cache.get(&embedding_id) {
+                candidates.push(RecommendationCandidate {
+                    score,
+                    ..candidate.clone()
+                });
+            }
+        }
+    }
+
+    let subscribed = subscriptions
+        .iter()
+        .filter_map(|s| s.channel_id)
+        .collect::<HashSet<_>>();
+    let read_rss = reads
+        .iter()
+        .filter_map(|r| r.rss_id)
+        .collect::<HashSet<_>>();
+    let read_news = reads
+        .iter()
+        .filter_map(|r| r.news_id)
+        .collect::<HashSet<_>>();
+
+    Ok(rank_candidates(
+        candidates,
+        &subscribed,
+        &read_rss,
+        &read_news,
+        config.limit,
+    ))
+}
+
+/// 인덱스 검색 결과의 임베딩 ID들에 해당하는 `since` 이후 발행된 RSS 아이템 또는 뉴스.
+async fn find_candidates(
+    pool: &MySqlPool,
+    category: &NewticleType,
+    embedding_ids: &[i32],
+    since: NaiveDateTime,
+) -> Result<HashMap<i32, RecommendationCandidate>, OmniNewsError> {
+    let candidates = match category {
+        NewticleType::Rss => search_repository::select_rss_search_results(
+            pool,
+            embedding_ids,
+            None,
+            Some(since),
+            None,
+        )
+        .await
+        .map(|items| {
+            items
+                .into_iter()
+                .filter_map(|item| {
+                    let candidate = RecommendationCandidate {
+                        rss_id: item.rss_id,
+                        news_id: None,
+                        channel_id: item.channel_id,
+                        title: item.rss_title,
+                        score: 0.0,
+                    };
+                    Some((item.embedding_id?, candidate))
+                })
+                .collect()
+        }),
+        NewticleType::News => {
+            search_repository::select_news_search_results(pool, embedding_ids, Some(since), None)
+                .await
+                .map(|items| {
+                    items
+                        .into_iter()
+                        .filter_map(|item| {
+                            let candidate = RecommendationCandidate {
+                                rss_id: None,
+                                news_id: item.news_id,
+                                channel_id: None,
+                                title: item.news_title,
+                                score: 0.0,
+                            };
+                            Some((item.embedding_id?, candidate))
+                        })
+                        .collect()
+                })
+        }
+        NewticleType::Channel => Ok(HashMap::new()),
+    };
+
+    candidates.map_err(|e| {
+        recommendation_error!(
+            "[Service] Failed to select recommendation candidate: {:?}",
+            e
+        );
+        OmniNewsError::Database(e)
+    })
+}
+
+/// 푸시 알림을 켜 둔 사용자에게 추천 상위 항목의 제목을 보냄. 토큰이 없으면 보내지 않고 `false`.
+async fn send_digest(
+    pool: &MySqlPool,
+    user: &RecommendationUser,
+    recommendations: &[RecommendationCandidate],
+) -> Result<bool, OmniNewsError> {
+    let Some(token) = user_service::get_user_fcm_token_by_user_id(pool, user.user_id).await? else {
+        return Ok(false);
+    };
+
+    let body = recommendations
+        .iter()
+        .filter_map(|candidate| candidate.title.as_deref())
+        .filter(|title| !title.is_empty())
+        .take(DIGEST_ITEMS)
+        .collect::<Vec<_>>()
+        .join("\n");
+    if body.is_empty() {
+        return Ok(false);
+    }
+
+    send_fcm_message(token, DIGEST_TITLE.to_string(), body)
+        .await
+        .map_err(|_| OmniNewsError::FirebaseError)?;
+    Ok(true)
+}
+
+/// 구독 채널 임베딩(가중치 1)과 읽은 항목 임베딩(가중치 `read_weight`)의 가중 평균을 정규화함.
+/// 사용할 벡터가 없거나 차원이 섞여 있으면 첫 벡터와 차원이 같은 것만 사용하고, 남는 게 없으면 `None`.
+fn interest_vector(
+    channel_vectors: &[Vec<f32>],
+    read_vectors: &[Vec<f32>],
+    read_weight: f32,
+) -> Option<Vec<f32>> {
+    let weighted = channel_vectors
+        .iter()
+        .map(|vector| (vector, 1.0))
+        .chain(read_vectors.iter().map(|vector| (vector, read_weight)))
+        .filter(|(vector, weight)| !vector.is_empty() && *weight > 0.0)
+        .collect::<Vec<_>>();
+    let dimension = weighted.first()?.0.len();
+
+    let mut interest = vec![0.0_f32; dimension];
+    for (vector, weight) in weighted.iter().filter(|(v, _)| v.len() == dimension) {
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        for (sum, x) in interest.iter_mut().zip(vector.iter()) {
+            *sum += x / norm * weight;
+        }
+    }
+
+    let norm = interest.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return None;
+    }
+    interest.iter_mut().for_each(|x| *x /= norm);
+    Some(interest)
+}
+
+/// 구독한 채널의 아이템과 읽은 항목을 빼고, 같은 항목은 한 번만 남겨 점수순으로 `limit`개를 고름.
+fn rank_candidates(
+    mut candidates: Vec<RecommendationCandidate>,
+    subscribed_channels: &HashSet<i32>,
+    read_rss: &HashSet<i32>,
+    read_news: &HashSet<i32>,
+    limit: usize,
+) -> Vec<RecommendationCandidate> {
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    let mut seen = HashSet::new();
+    candidates
+        .into_iter()
+        .filter(|c| {
+            !c.channel_id
+                .is_some_and(|id| subscribed_channels.contains(&id))
+        })
+        .filter(|c| !c.rss_id.is_some_and(|id| read_rss.contains(&id)))
+        .filter(|c| !c.news_id.is_some_and(|id| read_news.contains(&id)))
+        .filter(|c| seen.insert((c.rss_id, c.news_id)))
+        .take(limit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weights_read_items_in_interest_vector() {
+        let channels = vec![vec![1.0, 0.0]];
+        let reads = vec![vec![0.0, 2.0]];
+
+        let interest = interest_vector(&channels, &reads, 3.0).unwrap();
+        let expected = [1.0 / 10_f32.sqrt(), 3.0 / 10_f32.sqrt()];
+        assert!((interest[0] - expected[0]).abs() < 1e-6);
+        assert!((interest[1] - expected[1]).abs() < 1e-6);
+
+        assert_eq!(interest_vector(&channels, &[], 1.0), Some(vec![1.0, 0.0]));
+        assert_eq!(interest_vector(&[], &reads, 0.0), None);
+        assert_eq!(interest_vector(&[], &[], 1.0), None);
+    }
+
+    fn candidate(
+        rss_id: Option<i32>,
+        news_id: Option<i32>,
+        channel_id: i32,
+        score: f32,
+    ) -> RecommendationCandidate {
+        RecommendationCandidate {
+            rss_id,
+            news_id,
+            channel_id: rss_id.map(|_| channel_id),
+            title: None,
+            score,
+        }
+    }
+
+    #[test]
+    fn ranks_unseen_items_from_unsubscribed_channels() {
+        let candidates = vec![
+            candidate(Some(1), None, 10, 0.9),
+            candidate(Some(2), None, 20, 0.8),
+            candidate(None, Some(3), 0, 0.95),
+            candidate(None, Some(4), 0, 0.7),
+            candidate(Some(5), None, 30, 0.85),
+            candidate(None, Some(3), 0, 0.95),
+        ];
+        let subscribed = HashSet::from([10]);
+        let read_rss = HashSet::from([2]);
+        let read_news = HashSet::from([4]);
+
+        let ranked = rank_candidates(candidates, &subscribed, &read_rss, &read_news, 5);
+        let ids = ranked
+            .iter()
+            .map(|c| (c.rss_id, c.news_id))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![(None, Some(3)), (Some(5), None)]);
+
+        let candidates = vec![
+            candidate(Some(5), None, 30, 0.85),
+            candidate(None, Some(3), 0, 0.95),
+        ];
+        let ranked = rank_candidates(candidates, &subscribed, &read_rss, &read_news, 1);
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].news_id, Some(3));
+    }
+}
@@ -0,0 +1,400 @@
+use std::{
+    collections::BTreeSet,
+    env,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use chrono::{Duration, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use flate2::{write::GzEncoder, Compression};
+use sqlx::MySqlPool;
+
+use crate::{
+    config::{
+        env::{get_env_or, load_json_config},
+        logging::{NEWS_SCHEDULER, RSS_FETCH_AND_NOTIFICATION_SCHEDULER},
+    },
+    model::{
+        error::OmniNewsError,
+        retention::{
+            ExpiredRow, RetentionFilter, RetentionPolicy, RetentionReport, RetentionTable,
+        },
+    },
+    news_info, news_warn,
+    repository::{embedding_repository, retention_repository},
+};
+
+const DEFAULT_RETENTION_POLICIES_PATH: &str = "retention_policies.json";
+const DEFAULT_ARCHIVE_DIR: &str = "archive";
+
+/// `RETENTION_POLICIES_PATH`(기본 `retention_policies.json`)에서 보존 정책을 읽음.
+/// 파일이 없거나 잘못되면 기존 동작과 같이 뉴스만 7일 보관함.
+pub fn load_policies() -> Vec<RetentionPolicy> {
+    load_json_config("RETENTION_POLICIES_PATH", DEFAULT_RETENTION_POLICIES_PATH).unwrap_or_else(
+        |e| {
+            news_warn!(
+                "[Service] Failed to load retention policies, use defaults: {}",
+                e
+            );
+            default_policies()
+        },
+    )
+}
+
+/// 보존 정책 로그는 해당 테이블을 수집하는 스케줄러의 로그에 남김.
+fn log_target(table: &RetentionTable) -> &'static str {
+    match table {
+        RetentionTable::News => NEWS_SCHEDULER,
+        RetentionTable::RssItem => RSS_FETCH_AND_NOTIFICATION_SCHEDULER,
+    }
+}
+
+fn default_policies() -> Vec<RetentionPolicy> {
+    vec![RetentionPolicy {
+        table: RetentionTable::News,
+        category: None,
+        retention_days: 7,
+        archive: false,
+    }]
+}
+
+/// 정책별 삭제 조건을 만듦. 기본 정책(카테고리 없음)은 같은 테이블의 카테고리별 정책과 겹치지 않도록
+/// 해당 카테고리를 제외함. 기준 시각은 KST `today` 자정에서 보존 일수를 뺀 시각.
+/// 보존 일수가 날짜 범위를 넘는 정책은 경고를 남기고 건너뜀.
+fn build_filters(
+    policies: &[RetentionPolicy],
+    today: NaiveDate,
+) -> Vec<(RetentionPolicy, RetentionFilter)> {
+    policies
+        .iter()
+        .filter(|policy| policy.retention_days > 0)
+        .filter_map(|policy| {
+            let Some(cutoff) = Duration::try_days(policy.retention_days)
+                .and_then(|days| today.checked_sub_signed(days))
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+            else {
+                log::warn!(
+                    target: log_target(&policy.table),
+                    "[Service] Skip retention policy for {} with out-of-range retention_days: {}",
+                    policy.table.as_str(),
+                    policy.retention_days
+                );
+                return None;
+            };
+
+            let excluded = match policy.category {
+                Some(_) => Vec::new(),
+                None => policies
+                    .iter()
+                    .filter(|other| other.table == policy.table)
+                    .filter_map(|other| other.category.clone())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+            };
+
+            let filter = RetentionFilter {
+                cutoff,
+                category: policy.category.clone(),
+                excluded_categories: serde_json::to_string(&excluded).unwrap_or_default(),
+            };
+            Some((policy.clone(), filter))
+        })
+        .collect()
+}
+
+/// 보존 정책을 모두 적용하고 연결이 끊긴 임베딩을 정리함.
+/// `RETENTION_DRY_RUN=true`이면 삭제 없이 삭제 대상 개수만 보고함.
+pub async fn apply_retention_policies(
+    pool: &MySqlPool,
+) -> Result<Vec<RetentionReport>, OmniNewsError> {
+    let dry_run = get_env_or("RETENTION_DRY_RUN", false);
+    let today = Utc::now()
+        .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+        .date_naive();
+
+    let mut reports = Vec::new();
+    for (policy, filter) in build_filters(&load_policies(), today) {
+        match apply_policy(pool, &policy, &filter, dry_run).await {
+            Ok(report) => {
+                log::info!(
+                    target: log_target(&policy.table),
+                    "[Service] Retention{} {}({}) before {}: matched {}, archived {}, deleted {} rows and {} embeddings",
+                    if dry_run { " (dry-run)" } else { "" },
+                    report.table,
+                    report.category.as_deref().unwrap_or("*"),
+                    report.cutoff,
+                    report.matched,
+                    report.archived,
+                    report.deleted,
+                    report.deleted_embeddings
+                );
+                reports.push(report);
+            }
+            Err(e) => log::error!(
+                target: log_target(&policy.table),
+                "[Service] Failed to apply retention policy for {}: {:?}",
+                policy.table.as_str(),
+                e
+            ),
+        }
+    }
+
+    if dry_run {
+        let orphans = embedding_repository::count_orphan_embeddings(pool).await?;
+        news_info!(
+            "[Service] Retention (dry-run): {} orphan embeddings",
+            orphans
+        );
+    } else {
+        let deleted = embedding_repository::delete_orphan_embeddings(pool).await?;
+        news_info!("[Service] Retention: deleted {} orphan embeddings", deleted);
+    }
+
+    Ok(reports)
+}
+
+async fn apply_policy(
+    pool: &MySqlPool,
+    policy: &RetentionPolicy,
+    filter: &RetentionFilter,
+    dry_run: bool,
+) -> Result<RetentionReport, OmniNewsError> {
+    let matched = match policy.table {
+        RetentionTable::News => retention_repository::count_expired_news(pool, filter).await?,
+        RetentionTable::RssItem => {
+            retention_repository::count_expired_rss_items(pool, filter).await?
+        }
+    };
+
+    let mut report = RetentionReport {
+        table: policy.table.as_str(),
+        category: policy.category.clone(),
+        cutoff: filter.cutoff,
+        matched,
+        archived: 0,
+        deleted: 0,
+        deleted_embeddings: 0,
+    };
+    if dry_run || matched == 0 {
+        return Ok(report);
+    }
+
+    let batch_size = get_env_or("RETENTION_BATCH_SIZE", 500_i64);
+    let mut archive = if policy.archive {
+        let dir = env::var("RETENTION_ARCHIVE_DIR").unwrap_or(DEFAULT_ARCHIVE_DIR.to_string());
+        Some(ArchiveWriter::create(Path::new(&dir), policy)?)
+    } else {
+        None
+    };
+
+    loop {
+        let rows = match policy.table {
+            RetentionTable::News => {
+                retention_repository::select_expired_news(pool, filter, batch_size).await?
+            }
+            RetentionTable::RssItem => {
+                retention_repository::select_expired_rss_items(pool, filter, batch_size).await?
+            }
+        };
+        let Some(max_id) = rows.iter().map(|row| row.id).max() else {
+            break;
+        };
+
+        // 보관 파일에 먼저 기록한 뒤 삭제함
+        if let Some(archive) = archive.as_mut() {
+            archive.write(&rows)?;
+            report.archived += rows.len();
+        }
+
+        let (deleted_embeddings, deleted) = match policy.table {
+            RetentionTable::News => (
+                retention_repository::delete_expired_news_embeddings(pool, filter, max_id).await?,
+                retention_repository::delete_expired_news(pool, filter, max_id).await?,
+            ),
+            RetentionTable::RssItem => (
+                retention_repository::delete_expired_rss_item_embeddings(pool, filter, max_id)
+                    .await?,
+                retention_repository::delete_expired_rss_items(pool, filter, max_id).await?,
+            ),
+        };
+        report.deleted_embeddings += deleted_embeddings;
+        report.deleted += deleted;
+
+        if deleted == 0 {
+            break;
+        }
+    }
+
+    if let Some(archive) = archive {
+        let path = archive.finish()?;
+        log::info!(
+            target: log_target(&policy.table),
+            "[Service] Archived {} rows to {}",
+            report.archived,
+            path.display()
+        );
+    }
+
+    Ok(report)
+}
+
+/// 삭제할 행을 gzip 압축 JSONL 파일로 보관함.
+/// 파일 이름은 `{table}[_{category}]_{YYYYMMDDHHMMSS}.jsonl.gz`.
+struct ArchiveWriter {
+    path: PathBuf,
+    encoder: GzEncoder<File>,
+}
+
+impl ArchiveWriter {
+    fn create(dir: &Path, policy: &RetentionPolicy) -> Result<Self, OmniNewsError> {
+        fs::create_dir_all(dir)?;
+
+        let path = dir.join(archive_file_name(
+            policy,
+            Utc::now()
+                .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+                .naive_local(),
+        ));
+        let file = File::create(&path)?;
+
+        Ok(Self {
+            path,
+            encoder: GzEncoder::new(file, Compression::default()),
+        })
+    }
+
+    fn write(&mut self, rows: &[ExpiredRow]) -> Result<(), OmniNewsError> {
+        for row in rows {
+            if let Some(row_json) = &row.row_json {
+                writeln!(self.encoder, "{}", row_json)?;
+            }
+        }
+        // 삭제 전에 배치 단위로 디스크에 기록
+        self.encoder.flush()?;
+        self.encoder.get_ref().sync_data()?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<PathBuf, OmniNewsError> {
+        self.encoder.finish()?.sync_data()?;
+        Ok(self.path)
+    }
+}
+
+fn archive_file_name(policy: &RetentionPolicy, now: NaiveDateTime) -> String {
+    let category = policy
+        .category
+        .as_deref()
+        .map(|category| {
+            let sanitized = category
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect::<String>();
+            format!("_{}", sanitized)
+        })
+        .unwrap_or_default();
+
+    format!(
+        "{}{}_{}.jsonl.gz",
+        policy.table.as_str(),
+        category,
+        now.format("%Y%m%d%H%M%S")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(
+        table: RetentionTable,
+        category: Option<&str>,
+        retention_days: i64,
+    ) -> RetentionPolicy {
+        RetentionPolicy {
+            table,
+            category: category.map(str::to_string),
+            retention_days,
+            archive: false,
+        }
+    }
+
+    #[test]
+    fn parses_policy_file_format() {
+        let policies = serde_json::from_str::<Vec<RetentionPolicy>>(
+            r#"[
+                { "table": "news", "retention_days": 7 },
+                { "table": "rss_item", "category": "연예", "retention_days": 30, "archive": true }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(policies[0].table, RetentionTable::News);
+        assert!(!policies[0].archive);
+        assert_eq!(policies[1].table, RetentionTable::RssItem);
+        assert_eq!(policies[1].category.as_deref(), Some("연예"));
+        assert!(policies[1].archive);
+    }
+
+    #[test]
+    fn default_policy_excludes_categories_with_own_policy() {
+        let policies = vec![
+            policy(RetentionTable::News, None, 7),
+            policy(RetentionTable::News, Some("연예"), 3),
+            policy(RetentionTable::RssItem, Some("스포츠"), 30),
+            // 0일 이하는 비활성화로 봄
+            policy(RetentionTable::RssItem, None, 0),
+        ];
+        let today = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+        let filters = build_filters(&policies, today);
+
+        assert_eq!(filters.len(), 3);
+
+        let (_, news_default) = &filters[0];
+        assert_eq!(
+            news_default.cutoff,
+            NaiveDate::from_ymd_opt(2025, 10, 8)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+        assert!(news_default.category.is_none());
+        assert_eq!(news_default.excluded_categories, r#"["연예"]"#);
+
+        let (_, news_entertainment) = &filters[1];
+        assert_eq!(news_entertainment.category.as_deref(), Some("연예"));
+        assert_eq!(news_entertainment.excluded_categories, "[]");
+    }
+
+    #[test]
+    fn skips_policy_with_out_of_range_retention_days() {
+        let policies = vec![
+            policy(RetentionTable::News, None, 7),
+            policy(RetentionTable::RssItem, None, i64::MAX),
+        ];
+        let today = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+        let filters = build_filters(&policies, today);
+
+        assert_eq!(filters.len(), 1);
+        assert_eq!(filters[0].0.table, RetentionTable::News);
+    }
+
+    #[test]
+    fn sanitizes_category_in_archive_file_name() {
+        let now = NaiveDate::from_ymd_opt(2025, 10, 15)
+            .unwrap()
+            .and_hms_opt(3, 4, 5)
+            .unwrap();
+
+        assert_eq!(
+            archive_file_name(&policy(RetentionTable::News, Some("생활/문화"), 7), now),
+            "news_생활_문화_20251015030405.jsonl.gz"
+        );
+        assert_eq!(
+            archive_file_name(&policy(RetentionTable::RssItem, None, 90), now),
+            "rss_item_20251015030405.jsonl.gz"
+        );
+    }
+}
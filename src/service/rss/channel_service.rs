@@ -224,19 +224,35 @@ pub async fn get_rss_channel_by_id(
     }
 }
 
+/// 채널 정보와 임베딩을 갱신함. 임베딩은 제목/설명이 바뀐 경우에만 다시 만들고,
+/// 채널 정보가 모두 같으면 DB에 쓰지 않고 `false`를 반환함.
 pub async fn update_rss_channel_and_embedding(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
     rss_channel: &NewRssChannel,
     channel_id: i32,
 ) -> Result<bool, OmniNewsError> {
-    let _ = match embedding_update_channel(pool, embedding_service, rss_channel).await {
-        Ok(res) => res,
-        Err(e) => {
+    let stored = rss_channel_repository::select_channel_by_id(pool, channel_id)
+        .await
+        .ok();
+
+    // 임베딩 텍스트는 제목과 설명으로 만들므로, 둘 다 같으면 모델 호출을 생략함
+    if stored
+        .as_ref()
+        .is_none_or(|stored| is_embedding_text_changed(stored, rss_channel))
+    {
+        if let Err(e) = embedding_update_channel(pool, embedding_service, rss_channel).await {
             rss_info_error!("[Service] Failed to update embedding for channel: {:?}", e);
             return Err(e);
         }
-    };
+    }
+
+    if stored
+        .as_ref()
+        .is_some_and(|stored| !is_channel_changed(stored, rss_channel))
+    {
+        return Ok(false);
+    }
     match rss_channel_repository::update_rss_channel_by_id(pool, rss_channel, channel_id).await {
         Ok(res) => Ok(res),
         Err(e) => {
@@ -246,6 +262,21 @@ pub async fn update_rss_channel_and_embedding(
     }
 }
 
+fn is_embedding_text_changed(stored: &RssChannel, rss_channel: &NewRssChannel) -> bool {
+    stored.channel_title != rss_channel.channel_title
+        || stored.channel_description != rss_channel.channel_description
+}
+
+/// 저장할 채널 정보가 바뀌었는지 여부. 제목/설명 외의 정보만 바뀌어도 DB는 갱신함.
+fn is_channel_changed(stored: &RssChannel, rss_channel: &NewRssChannel) -> bool {
+    is_embedding_text_changed(stored, rss_channel)
+        || stored.channel_link != rss_channel.channel_link
+        || stored.channel_image_url != rss_channel.channel_image_url
+        || stored.channel_language != rss_channel.channel_language
+        || stored.rss_generator != rss_channel.rss_generator
+        || stored.channel_rank != rss_channel.channel_rank
+}
+
 pub async fn embedding_update_channel(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
@@ -268,12 +299,15 @@ pub async fn embedding_update_channel(
         rss_id: None,
         news_id: None,
         embedding_source_rank: Some(0),
+        embedding_model_id: None,
+        embedding_model_version: None,
+        embedding_content_hash: None,
     };
     embedding_service::update_embedding(pool, embedding_service, embedding_text, embedding).await?;
     Ok(channel_id)
 }
 
-fn prepare_embedding_text(title: &str, description: &str) -> String {
+pub fn prepare_embedding_text(title: &str, description: &str) -> String {
     // 1. HTML 태그 제거
     let clean_description = remove_html_tags(description);
 
@@ -298,8 +332,13 @@ fn prepare_embedding_text(title: &str, description: &str) -> String {
         .to_string();
 
     // 4. 텍스트 길이 제한 (임베딩 모델의 최대 입력 길이 고려)
+    // 한글이 잘리지 않도록 문자 경계에서 자름
     if text.len() > 512 {
-        text.truncate(512);
+        let mut end = 512;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
     }
 
     // 5. 제목 반복으로 중요성 강조 (선택적)
@@ -427,3 +466,50 @@ pub async fn parse_rss_link_to_channel_with_web_driver(
 
     Ok(channel)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(title: &str, description: &str) -> NewRssChannel {
+        NewRssChannel {
+            channel_title: Some(title.to_string()),
+            channel_link: Some("https://example.com".to_string()),
+            channel_description: Some(description.to_string()),
+            channel_image_url: None,
+            channel_language: Some("ko".to_string()),
+            rss_generator: Some("default".to_string()),
+            channel_rank: Some(0),
+            channel_rss_link: Some("https://example.com/rss".to_string()),
+        }
+    }
+
+    fn stored(rss_channel: &NewRssChannel) -> RssChannel {
+        RssChannel {
+            channel_id: Some(1),
+            channel_title: rss_channel.channel_title.clone(),
+            channel_link: rss_channel.channel_link.clone(),
+            channel_description: rss_channel.channel_description.clone(),
+            channel_image_url: rss_channel.channel_image_url.clone(),
+            channel_language: rss_channel.channel_language.clone(),
+            rss_generator: rss_channel.rss_generator.clone(),
+            channel_rank: rss_channel.channel_rank,
+            channel_rss_link: rss_channel.channel_rss_link.clone(),
+        }
+    }
+
+    #[test]
+    fn detects_unchanged_channel() {
+        let rss_channel = channel("테크 블로그", "개발 이야기");
+        assert!(!is_channel_changed(&stored(&rss_channel), &rss_channel));
+
+        let updated = channel("테크 블로그", "개발과 운영 이야기");
+        assert!(is_channel_changed(&stored(&rss_channel), &updated));
+    }
+
+    #[test]
+    fn truncates_embedding_text_at_char_boundary() {
+        let text = prepare_embedding_text("제목", &"가".repeat(400));
+        assert!(text.starts_with("제목: 제목. 내용: 가"));
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{
+    config::env::get_env_or,
     model::{embedding::NewEmbedding, error::OmniNewsError, rss::NewRssItem},
     repository::rss_item_repository,
-    rss_fetch_and_notification_error, rss_fetch_and_notification_warn,
-    service::embedding_service,
-    utils::embedding_util::EmbeddingService,
+    rss_fetch_and_notification_error, rss_fetch_and_notification_info,
+    rss_fetch_and_notification_warn,
+    service::{analysis_service, embedding_service},
+    utils::{
+        date_util::parse_news_date, embedding_util::EmbeddingService, summarizer::SummarizerChain,
+    },
 };
-use chrono::FixedOffset;
-use chrono::{DateTime, NaiveDateTime};
+use chrono::NaiveDateTime;
 use scraper::{Html, Selector};
 use sqlx::MySqlPool;
 
@@ -48,6 +51,9 @@ pub async fn create_rss_item_and_embedding(
         rss_id: Some(item_id),
         news_id: None,
         embedding_source_rank: Some(0),
+        embedding_model_id: None,
+        embedding_model_version: None,
+        embedding_content_hash: None,
     };
 
     embedding_service::create_embedding(pool, embedding_service, sentence, embedding).await?;
@@ -83,14 +89,7 @@ fn use_channel_url_if_none(link: Option<String>, channel_image_url: String) -> S
 }
 
 pub fn parse_pub_date(pub_date_str: Option<&str>) -> Option<NaiveDateTime> {
-    pub_date_str.and_then(|date_str| {
-        if let Ok(dt) = DateTime::parse_from_rfc2822(date_str) {
-            let kst = FixedOffset::east_opt(9 * 3600).unwrap();
-            Some(dt.with_timezone(&kst).naive_local())
-        } else {
-            None
-        }
-    })
+    pub_date_str.and_then(parse_news_date)
 }
 
 async fn store_rss_item(pool: &MySqlPool, mut rss_item: NewRssItem) -> Result<i32, OmniNewsError> {
@@ -146,3 +145,74 @@ pub async fn is_exist_rss_item_by_link(
         Err(_) => Ok(false),
     }
 }
+
+/// 아직 분석되지 않은 RSS 아이템을 `RSS_ANALYSIS_BATCH_SIZE`개씩 분석해 저장함.
+/// 예산이 소진되면 남은 아이템은 다음 주기로 미룸.
+/// 분석에 실패한 아이템은 대기 상태로 두고 `RSS_ANALYSIS_MAX_ATTEMPTS`번까지 다시 시도함.
+pub async fn analyze_pending_rss_items(
+    pool: &MySqlPool,
+    summarizer: &SummarizerChain,
+) -> Result<i32, OmniNewsError> {
+    let batch_size = get_env_or("RSS_ANALYSIS_BATCH_SIZE", 20_i64);
+    let max_attempts = get_env_or("RSS_ANALYSIS_MAX_ATTEMPTS", 3_i32);
+    let items = rss_item_repository::select_unanalyzed_rss_items(pool, max_attempts, batch_size)
+        .await
+        .map_err(|e| {
+            rss_fetch_and_notification_error!(
+                "[Service] Failed to select unanalyzed rss items: {:?}",
+                e
+            );
+            OmniNewsError::Database(e)
+        })?;
+
+    let mut analyzed = 0;
+    for item in items {
+        let Some(rss_id) = item.rss_id else {
+            continue;
+        };
+        let content = format!(
+            "{}\n{}",
+            item.rss_title.unwrap_or_default(),
+            item.rss_description.unwrap_or_default()
+        );
+
+        let analysis = match analysis_service::analyze_content(pool, summarizer, content).await {
+            Ok(Some(analysis)) => analysis,
+            Ok(None) => break,
+            Err(e) => {
+                // 완료 처리하지 않고 실패 횟수만 늘려 다음 주기에 다시 시도함
+                rss_fetch_and_notification_warn!(
+                    "[Service] Failed to analyze rss item {}: {}",
+                    rss_id,
+                    e
+                );
+                increase_analysis_attempts(pool, rss_id).await;
+                continue;
+            }
+        };
+
+        match rss_item_repository::update_rss_item_analysis(pool, rss_id, &analysis).await {
+            Ok(_) => analyzed += 1,
+            Err(e) => {
+                // 저장에 실패한 항목도 시도 횟수를 늘려 같은 항목이 계속 재분석되지 않게 함
+                rss_fetch_and_notification_error!(
+                    "[Service] Failed to update rss item analysis: {:?}",
+                    e
+                );
+                increase_analysis_attempts(pool, rss_id).await;
+            }
+        }
+    }
+
+    rss_fetch_and_notification_info!("[Service] Analyzed {} rss items", analyzed);
+    Ok(analyzed)
+}
+
+async fn increase_analysis_attempts(pool: &MySqlPool, rss_id: i32) {
+    if let Err(e) = rss_item_repository::increase_rss_item_analysis_attempts(pool, rss_id).await {
+        rss_fetch_and_notification_error!(
+            "[Service] Failed to update rss item analysis attempts: {:?}",
+            e
+        );
+    }
+}
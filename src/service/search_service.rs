@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use sqlx::MySqlPool;
+
+use crate::{
+    config::env::get_env_or,
+    model::{
+        error::OmniNewsError,
+        rss::NewticleType,
+        search::{SearchFilter, SearchHit, SearchItem},
+    },
+    repository::{embedding_repository, search_repository},
+    utils::{
+        embedding_util::{decode_embedding, EmbeddingService},
+        index_searcher::IndexSearcher,
+    },
+};
+
+// 뉴스는 국내 소스에서만 수집하므로 언어 컬럼 없이 한국어로 취급함
+const NEWS_LANGUAGE: &str = "ko";
+
+/// 채널, RSS 아이템, 뉴스 하나와 비슷한 같은 종류의 항목. 기준 항목은 결과에서 제외함.
+pub async fn find_similar(
+    pool: &MySqlPool,
+    searcher: &IndexSearcher,
+    category: &NewticleType,
+    id: i32,
+    filter: &SearchFilter,
+    limit: usize,
+) -> Result<Vec<SearchHit>, OmniNewsError> {
+    let model_version = active_model_version(pool).await?;
+    let embedding = match category {
+        NewticleType::Channel => {
+            embedding_repository::select_channel_embedding(pool, id, model_version).await
+        }
+        NewticleType::Rss => {
+            embedding_repository::select_rss_embedding(pool, id, model_version).await
+        }
+        NewticleType::News => {
+            embedding_repository::select_news_embedding(pool, id, model_version).await
+        }
+    }
+    .map_err(|e| {
+        error!(
+            "[Service] Failed to select {:?} embedding: {:?}",
+            category, e
+        );
+        OmniNewsError::Database(e)
+    })?;
+    let Some(embedding) = embedding else {
+        return Err(OmniNewsError::NotFound(format!(
+            "{:?} {} embedding",
+            category, id
+        )));
+    };
+
+    let query = decode_embedding(embedding.embedding_value.as_deref().unwrap_or_default());
+    search(
+        pool,
+        searcher,
+        category,
+        model_version,
+        query,
+        filter,
+        limit,
+        embedding.embedding_id,
+    )
+    .await
+}
+
+/// 자유 텍스트를 임베딩해 가까운 항목을 찾음. 재임베딩 중이라 서버의 모델 버전이
+/// `active` 버전과 다르면 벡터 공간이 달라 검색하지 않음.
+pub async fn search_by_text(
+    pool: &MySqlPool,
+    searcher: &IndexSearcher,
+    embedding_service: &EmbeddingService,
+    category: &NewticleType,
+    text: &str,
+    filter: &SearchFilter,
+    limit: usize,
+) -> Result<Vec<SearchHit>, OmniNewsError> {
+    let model_version = active_model_version(pool).await?;
+    if embedding_service.model_version() != model_version {
+        return Err(OmniNewsError::NotFound(format!(
+            "Embedding model version {} is not active (active: {})",
+            embedding_service.model_version(),
+            model_version
+        )));
+    }
+
+    let query = embedding_service
+        .embed_many(vec![text.to_string()])
+        .await?
+        .pop()
+        .ok_or(OmniNewsError::Embedding)?;
+    search(
+        pool,
+        searcher,
+        category,
+        model_version,
+        query,
+        filter,
+        limit,
+        None,
+    )
+    .await
+}
+
+async fn active_model_version(pool: &MySqlPool) -> Result<i32, OmniNewsError> {
+    embedding_repository::select_active_embedding_model(pool)
+        .await
+        .map_err(OmniNewsError::Database)?
+        .and_then(|model| model.embedding_model_version)
+        .ok_or_else(|| OmniNewsError::NotFound("Active embedding model".to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn search(
+    pool: &MySqlPool,
+    searcher: &IndexSearcher,
+    category: &NewticleType,
+    model_version: i32,
+    query: Vec<f32>,
+    filter: &SearchFilter,
+    limit: usize,
+    exclude_embedding_id: Option<i32>,
+) -> Result<Vec<SearchHit>, OmniNewsError> {
+    if *category == NewticleType::News && !matches_news_language(filter.language.as_deref()) {
+        return Ok(Vec::new());
+    }
+
+    // 필터에 걸리거나 삭제된 항목이 빠져도 `limit`개를 채울 수 있도록 후보를 더 가져옴
+    let candidates = limit * get_env_or("SEARCH_CANDIDATE_FACTOR", 5_usize).max(1) + 1;
+    let hits = searcher
+        .search(category, model_version, query, candidates)
+        .await?;
+
+    let hits = hits
+        .into_iter()
+        .filter(|(embedding_id, _)| Some(*embedding_id) != exclude_embedding_id)
+        .collect::<Vec<_>>();
+    let embedding_ids = hits.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+
+    let language = filter.language.as_deref();
+    let mut items = match category {
+        NewticleType::Channel => {
+            search_repository::select_channel_search_results(pool, &embedding_ids, language)
+                .await
+                .map(|items| {
+                    items
+                        .into_iter()
+                        .filter_map(|item| Some((item.embedding_id?, SearchItem::Channel(item))))
+                        .collect::<HashMap<_, _>>()
+                })
+        }
+        NewticleType::Rss => search_repository::select_rss_search_results(
+            pool,
+            &embedding_ids,
+            language,
+            filter.since,
+            filter.until,
+        )
+        .await
+        .map(|items| {
+            items
+                .into_iter()
+                .filter_map(|item| Some((item.embedding_id?, SearchItem::Rss(item))))
+                .collect::<HashMap<_, _>>()
+        }),
+        NewticleType::News => search_repository::select_news_search_results(
+            pool,
+            &embedding_ids,
+            filter.since,
+            filter.until,
+        )
+        .await
+        .map(|items| {
+            items
+                .into_iter()
+                .filter_map(|item| Some((item.embedding_id?, SearchItem::News(item))))
+                .collect::<HashMap<_, _>>()
+        }),
+    }
+    .map_err(|e| {
+        error!("[Service] Failed to select search results: {:?}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    // 조회 결과는 순서가 없으므로 인덱스 검색 순서(유사도 순)로 맞춤
+    Ok(hits
+        .into_iter()
+        .filter_map(|(embedding_id, score)| {
+            let item = items.remove(&embedding_id)?;
+            Some(SearchHit { score, item })
+        })
+        .take(limit)
+        .collect())
+}
+
+/// 뉴스 언어(`ko`)와 언어 필터를 양방향 접두사로 비교함. `ko-KR`, `ko`는 한국어로 보고,
+/// 두 글자보다 짧은 필터는 언어 코드가 아니므로 일치하지 않음.
+fn matches_news_language(language: Option<&str>) -> bool {
+    language.is_none_or(|language| {
+        let language = language.to_lowercase();
+        // 한 글자는 언어 코드로 볼 수 없으므로 "k" 같은 값은 일치하지 않는 것으로 봄
+        language.chars().count() >= 2
+            && (NEWS_LANGUAGE.starts_with(&language) || language.starts_with(NEWS_LANGUAGE))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_news_language_by_prefix_in_both_directions() {
+        assert!(matches_news_language(None));
+        assert!(matches_news_language(Some("ko")));
+        assert!(matches_news_language(Some("ko-KR")));
+        assert!(!matches_news_language(Some("K")));
+        assert!(!matches_news_language(Some("en")));
+        assert!(!matches_news_language(Some("en-US")));
+    }
+}
@@ -0,0 +1,63 @@
+use sqlx::MySqlPool;
+
+use crate::{
+    model::{analysis::ArticleAnalysis, error::OmniNewsError, summary_cache::NewSummaryCache},
+    news_warn,
+    repository::summary_cache_repository,
+    utils::summarizer::{Summary, SUMMARY_PROMPT_VERSION},
+};
+
+/// 현재 프롬프트 버전으로 캐시된 분석 결과 중 `models` 우선순위가 가장 높은 결과를 반환함.
+pub async fn find_cached_analysis(
+    pool: &MySqlPool,
+    content_hash: &str,
+    models: &[String],
+) -> Option<ArticleAnalysis> {
+    let caches = match summary_cache_repository::select_summary_caches(
+        pool,
+        content_hash,
+        SUMMARY_PROMPT_VERSION,
+    )
+    .await
+    {
+        Ok(caches) => caches,
+        Err(e) => {
+            news_warn!("[Service] Failed to select summary cache: {:?}", e);
+            return None;
+        }
+    };
+
+    models.iter().find_map(|model| {
+        caches
+            .iter()
+            .find(|cache| cache.model.as_deref() == Some(model.as_str()))
+            .and_then(|cache| cache.analysis.as_deref())
+            .and_then(|analysis| serde_json::from_str(analysis).ok())
+    })
+}
+
+pub async fn save_analysis(
+    pool: &MySqlPool,
+    content_hash: &str,
+    summary: &Summary,
+) -> Result<(), OmniNewsError> {
+    let cache = NewSummaryCache {
+        content_hash: content_hash.to_string(),
+        prompt_version: SUMMARY_PROMPT_VERSION,
+        model: summary.model.clone(),
+        summary: summary.analysis.summary.clone(),
+        analysis: serde_json::to_string(&summary.analysis).ok(),
+    };
+
+    summary_cache_repository::upsert_summary_cache(pool, &cache)
+        .await
+        .map(|_| ())
+        .map_err(OmniNewsError::Database)
+}
+
+/// 프롬프트 버전이 바뀌어 더 이상 사용되지 않는 캐시만 삭제하고 삭제한 개수를 반환함.
+pub async fn purge_stale_summary_caches(pool: &MySqlPool) -> Result<i32, OmniNewsError> {
+    summary_cache_repository::delete_summary_caches_except_version(pool, SUMMARY_PROMPT_VERSION)
+        .await
+        .map_err(OmniNewsError::Database)
+}
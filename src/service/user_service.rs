@@ -2,6 +2,7 @@ use sqlx::MySqlPool;
 
 use crate::{
     model::{error::OmniNewsError, fcm_token::FcmTokenUser},
+    recommendation_error,
     repository::user_repository,
     rss_fetch_and_notification_error,
 };
@@ -25,3 +26,16 @@ pub async fn get_users_fcm_token_subscribed_channel_by_channel_id(
         }
     }
 }
+
+pub async fn get_user_fcm_token_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<FcmTokenUser>, OmniNewsError> {
+    match user_repository::select_user_fcm_token_by_user_id(pool, user_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            recommendation_error!("[Service] Failed to select user fcm token: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
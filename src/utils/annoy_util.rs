@@ -1,92 +1,916 @@
-use std::path::PathBuf;
+use std::{
+    collections::HashSet,
+    env,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
 
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sqlx::MySqlPool;
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::{self, JoinHandle},
+};
 
 use crate::{
-    annoy_info,
-    model::{embedding::Embedding, error::OmniNewsError, rss::NewticleType},
+    annoy_error, annoy_info, annoy_warn,
+    config::env::get_env_or,
+    model::{
+        embedding::{Embedding, EmbeddingIndexStats},
+        error::OmniNewsError,
+        rss::NewticleType,
+    },
+    repository::embedding_repository,
     service::embedding_service,
-    utils::embedding_util::decode_embedding,
+    utils::{
+        embedding_util::decode_embedding,
+        index_publisher::{IndexPublishers, PublishedIndex},
+        vector_index::VectorIndexType,
+    },
 };
 
+const DEFAULT_INDEX_DIR: &str = "../resources";
+
+/// 한 번의 인덱스 빌드 결과. 인덱스를 읽는 쪽은 `dimension`으로 인덱스를 열고
+/// `model_id`/`model_version`으로 같은 모델의 쿼리 벡터인지 확인함.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexVersion {
+    version: u64,
+    // 버전별로 보관되는 인덱스 파일 이름 (`{index}.v{version}`)
+    file: String,
+    model_id: String,
+    model_version: i32,
+    dimension: usize,
+    items: usize,
+    rejected: usize,
+    built_at: String,
+    // 빌드에 사용한 임베딩 행 수와 워터마크. 다음 빌드가 필요한지 판단할 때 사용함
+    #[serde(default)]
+    source_rows: i64,
+    #[serde(default)]
+    max_embedding_id: Option<i32>,
+    #[serde(default)]
+    max_updated_at: Option<NaiveDateTime>,
+}
+
+/// 인덱스 옆 `{index}.manifest.json`. `current`가 현재 게시된 버전이고,
+/// `versions`에는 롤백용으로 보관 중인 버전이 최신순으로 들어 있음.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexManifest {
+    current: Option<u64>,
+    versions: Vec<IndexVersion>,
+}
+
+impl IndexManifest {
+    fn next_version(&self) -> u64 {
+        self.versions.iter().map(|v| v.version).max().unwrap_or(0) + 1
+    }
+
+    /// 새 버전을 현재 버전으로 기록하고, 현재 버전 외에 `keep`개만 남긴 뒤 밀려난 버전을 반환함.
+    fn publish(&mut self, version: IndexVersion, keep: usize) -> Vec<IndexVersion> {
+        self.current = Some(version.version);
+        self.versions.insert(0, version);
+        self.versions.sort_by(|a, b| b.version.cmp(&a.version));
+        self.versions.split_off((keep + 1).min(self.versions.len()))
+    }
+}
+
+/// 검색에 사용할 현재 게시된 인덱스. 게시 경로는 다음 빌드에서 바뀌므로 버전 파일을 가리킴.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrentIndex {
+    pub kind: &'static str,
+    pub path: PathBuf,
+    pub index_type: VectorIndexType,
+    pub version: u64,
+    pub model_version: i32,
+    pub dimension: usize,
+}
+
+/// 인덱스 종류별 설정. 이전 빌드 이후 바뀐 임베딩이 `threshold`개 이상이면 다시 만듦.
+struct IndexTarget {
+    category: NewticleType,
+    kind: &'static str,
+    // 인덱스 파일 이름. 확장자는 인덱스 종류에 따라 `.ann` 또는 `.hnsw`
+    name: &'static str,
+    threshold: i64,
+}
+
+fn index_targets() -> [IndexTarget; 3] {
+    [
+        IndexTarget {
+            category: NewticleType::Channel,
+            kind: "channel",
+            name: "channel_embeddings",
+            threshold: get_env_or("ANNOY_REBUILD_THRESHOLD_CHANNEL", 1_i64),
+        },
+        IndexTarget {
+            category: NewticleType::Rss,
+            kind: "rss",
+            name: "rss_embeddings",
+            threshold: get_env_or("ANNOY_REBUILD_THRESHOLD_RSS", 50_i64),
+        },
+        IndexTarget {
+            category: NewticleType::News,
+            kind: "news",
+            name: "news_embeddings",
+            threshold: get_env_or("ANNOY_REBUILD_THRESHOLD_NEWS", 50_i64),
+        },
+    ]
+}
+
+/// 인덱스를 만드는 임베딩 모델.
+struct IndexModel<'a> {
+    id: &'a str,
+    version: i32,
+    dimension: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum RebuildReason {
+    // 게시된 인덱스가 없음
+    Missing,
+    // 활성 모델이 바뀜
+    ModelChanged,
+    // 바뀐 임베딩이 임계값 이상
+    Changed(i64),
+    // 임계값 미만이지만 마지막 빌드 후 `ANNOY_REBUILD_MAX_AGE_MINUTES`가 지남
+    Expired(i64),
+}
+
+/// 현재 게시된 버전과 임베딩 현황을 비교해 다시 만들어야 하는지 판단함.
+/// 바뀐 행 수는 새로 추가된 행, 갱신된 행, 삭제된 행(이전 행 수 + 추가 - 현재 행 수)의 합.
+fn rebuild_reason(
+    current: Option<&IndexVersion>,
+    stats: &EmbeddingIndexStats,
+    model: &IndexModel,
+    threshold: i64,
+    max_age: Duration,
+    now: DateTime<Utc>,
+) -> Option<RebuildReason> {
+    let Some(current) = current else {
+        return Some(RebuildReason::Missing);
+    };
+    if current.model_id != model.id
+        || current.model_version != model.version
+        || current.dimension != model.dimension
+    {
+        return Some(RebuildReason::ModelChanged);
+    }
+
+    let deleted = (current.source_rows + stats.new_rows - stats.row_count).max(0);
+    let changes = stats.new_rows + stats.updated_rows + deleted;
+    if changes == 0 {
+        return None;
+    }
+    if changes >= threshold {
+        return Some(RebuildReason::Changed(changes));
+    }
+
+    let expired = DateTime::parse_from_rfc3339(&current.built_at)
+        .map(|built_at| now.signed_duration_since(built_at) >= max_age)
+        .unwrap_or(true);
+    expired.then_some(RebuildReason::Expired(changes))
+}
+
+/// `active` 모델 버전의 임베딩으로 `ANNOY_INDEX_DIR`(기본 `../resources`)에 인덱스를 만들고,
+/// `ANNOY_PUBLISH_TARGETS`에 설정된 원격 저장소에도 올림.
+/// 이전 빌드 이후 바뀐 임베딩이 종류별 임계값 이상인 인덱스만 다시 만듦.
+/// 재임베딩 중인 새 버전은 활성화되기 전까지 인덱스에 포함되지 않음.
 pub async fn save_annoy(pool: &MySqlPool) -> Result<(), OmniNewsError> {
-    let embeddings_channel =
-        embedding_service::find_all_embeddings_by(pool, NewticleType::Channel).await?;
-    let embeddings_rss = embedding_service::find_all_embeddings_by(pool, NewticleType::Rss).await?;
-    let embeddings_news =
-        embedding_service::find_all_embeddings_by(pool, NewticleType::News).await?;
-    save_channel_annoy(embeddings_channel).await?;
-    save_rss_annoy(embeddings_rss).await?;
-    save_news_annoy(embeddings_news).await?;
+    let active_model = embedding_repository::select_active_embedding_model(pool)
+        .await
+        .map_err(|e| {
+            annoy_error!("[Service] Failed to select active embedding model: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let Some(active_model) = active_model else {
+        annoy_warn!("[Service] No active embedding model, skip saving annoy.");
+        return Ok(());
+    };
+    // 차원을 모르면 조회 시 쿼리 벡터와 비교할 수 없는 인덱스가 되므로 만들지 않음
+    let Some(dimension) = active_model.embedding_model_dimension.filter(|&d| d > 0) else {
+        annoy_error!(
+            "[Service] Active embedding model {:?} has no dimension, skip saving annoy.",
+            active_model.embedding_model_id
+        );
+        return Err(OmniNewsError::Embedding);
+    };
+    let model = IndexModel {
+        id: active_model
+            .embedding_model_id
+            .as_deref()
+            .unwrap_or_default(),
+        version: active_model.embedding_model_version.unwrap_or_default(),
+        dimension: dimension as usize,
+    };
+
+    let index_dir = index_dir();
+    fs::create_dir_all(&index_dir)?;
+    let index_type = VectorIndexType::from_env();
+
+    let mut published = Vec::new();
+    for target in index_targets() {
+        let path = index_dir.join(format!("{}.{}", target.name, index_type.extension()));
+        // 한 종류가 실패해도 나머지 인덱스는 계속 만들고, 성공한 것만 게시함
+        match save_index(pool, &target, &path, &model, index_type).await {
+            Ok(Some(index)) => published.push(index),
+            Ok(None) => (),
+            Err(e) => annoy_error!("[Service] Failed to save {} index: {}", target.name, e),
+        }
+    }
+
+    // 로컬 게시가 모두 끝난 뒤 원격에 올림. 원격 게시 실패는 로컬 인덱스에 영향을 주지 않음
+    if !published.is_empty() {
+        let publishers = IndexPublishers::from_env();
+        for index in &published {
+            publishers.publish(index).await;
+        }
+    }
 
     Ok(())
 }
 
-async fn save_channel_annoy(embeddings: Vec<Embedding>) -> Result<(), OmniNewsError> {
-    if embeddings.is_empty() {
-        annoy_info!("[Service] No embeddings found for channel.");
-        return Ok(());
+/// `category` 종류의 현재 게시된 인덱스. 게시된 인덱스가 없거나 버전 파일이 지워졌으면 `None`.
+pub fn current_index(category: &NewticleType) -> Option<CurrentIndex> {
+    let target = index_targets()
+        .into_iter()
+        .find(|target| &target.category == category)?;
+    let index_type = VectorIndexType::from_env();
+    let path = index_dir().join(format!("{}.{}", target.name, index_type.extension()));
+
+    let manifest = read_manifest(&path);
+    let current = manifest
+        .current
+        .and_then(|current| manifest.versions.into_iter().find(|v| v.version == current))?;
+    let versioned = versioned_path(&path, current.version);
+    if !versioned.exists() {
+        return None;
     }
-    let embedding_dim = embeddings[0].embedding_value.as_ref().unwrap().len();
+
+    Some(CurrentIndex {
+        kind: target.kind,
+        path: versioned,
+        index_type,
+        version: current.version,
+        model_version: current.model_version,
+        dimension: current.dimension,
+    })
+}
+
+/// 바뀐 임베딩이 충분하면 인덱스를 다시 만들어 로컬에 게시하고, 게시한 버전 정보를 반환함.
+/// 다시 만들 필요가 없거나 게시할 벡터가 없으면 `None`.
+async fn save_index(
+    pool: &MySqlPool,
+    target: &IndexTarget,
+    path: &Path,
+    model: &IndexModel<'_>,
+    index_type: VectorIndexType,
+) -> Result<Option<PublishedIndex>, OmniNewsError> {
+    let kind = target.kind;
+    let mut manifest = read_manifest(path);
+    // 매니페스트만 남고 인덱스 파일이 없으면 게시된 인덱스가 없는 것으로 봄
+    let current = manifest
+        .current
+        .and_then(|current| manifest.versions.iter().find(|v| v.version == current))
+        .filter(|_| path.exists());
+
+    let stats = embedding_service::find_embedding_index_stats(
+        pool,
+        &target.category,
+        model.version,
+        current.and_then(|v| v.max_embedding_id).unwrap_or(0),
+        current.and_then(|v| v.max_updated_at),
+    )
+    .await?;
+    let Some(max_embedding_id) = stats.max_embedding_id else {
+        if current.is_some() {
+            annoy_info!("[Service] No embeddings found for {}.", kind);
+        }
+        unpublish_index(path);
+        return Ok(None);
+    };
+
+    let max_age = Duration::minutes(get_env_or("ANNOY_REBUILD_MAX_AGE_MINUTES", 60_i64));
+    let Some(reason) = rebuild_reason(
+        current,
+        &stats,
+        model,
+        target.threshold,
+        max_age,
+        Utc::now(),
+    ) else {
+        return Ok(None);
+    };
+
+    // 바꿀 수 있는 인덱스는 같은 모델의 이전 버전에 바뀐 임베딩만 반영함
+    let base = match (&reason, current) {
+        (RebuildReason::Changed(_) | RebuildReason::Expired(_), Some(current))
+            if index_type.is_incremental() =>
+        {
+            Some(IndexBase {
+                path: versioned_path(path, current.version),
+                max_embedding_id: current.max_embedding_id.unwrap_or(0),
+                max_updated_at: current.max_updated_at,
+                source_rows: current.source_rows,
+            })
+        }
+        _ => None,
+    }
+    .filter(|base| base.path.exists());
     annoy_info!(
-        "[Service] Rss Channel Embedding dimension: {}",
-        embedding_dim
+        "[Service] {} {} index ({} rows): {:?}",
+        if base.is_some() {
+            "Updating"
+        } else {
+            "Rebuilding"
+        },
+        kind,
+        stats.row_count,
+        reason
     );
 
-    let annoy = rannoy::Rannoy::new(384);
-    annoy.set_seed(123);
+    let version = manifest.next_version();
+    let versioned = versioned_path(path, version);
+    // 임시 파일에 쓰고 디스크에 반영한 뒤 이름을 바꿔, 읽는 쪽이 쓰는 중인 파일을 보지 않도록 함
+    let tmp = tmp_path(&versioned);
+
+    let build = |base| {
+        build_index(
+            pool,
+            target,
+            model,
+            index_type,
+            &stats,
+            max_embedding_id,
+            base,
+            &tmp,
+        )
+    };
+    let built = match build(base.as_ref()).await {
+        Err(e) if base.is_some() => {
+            annoy_warn!(
+                "[Service] Failed to update {} index from v{:?}, rebuild from scratch: {:?}",
+                kind,
+                manifest.current,
+                e
+            );
+            build(None).await?
+        }
+        result => result?,
+    };
+    let Some(built) = built else {
+        unpublish_index(path);
+        return Ok(None);
+    };
+
+    fs::rename(&tmp, &versioned)?;
+    publish_file(&versioned, path)?;
 
-    for embedding in embeddings.iter() {
-        let decoded_embedding = decode_embedding(embedding.embedding_value.as_ref().unwrap());
-        annoy.add_item(embedding.embedding_id.unwrap(), decoded_embedding.as_ref());
+    let built_at = Utc::now().to_rfc3339();
+    let index_version = IndexVersion {
+        version,
+        file: file_name(&versioned),
+        model_id: model.id.to_string(),
+        model_version: model.version,
+        dimension: model.dimension,
+        items: built.items,
+        rejected: built.rejected,
+        built_at: built_at.clone(),
+        source_rows: stats.row_count,
+        max_embedding_id: stats.max_embedding_id,
+        max_updated_at: stats.max_updated_at,
+    };
+    let keep = get_env_or("ANNOY_KEEP_VERSIONS", 3_usize);
+    let expired = manifest.publish(index_version, keep);
+    write_atomic(
+        &manifest_path(path),
+        serde_json::to_string_pretty(&manifest)
+            .unwrap_or_default()
+            .as_bytes(),
+    )?;
+    for expired in expired {
+        let expired_path = versioned_path(path, expired.version);
+        if let Err(e) = fs::remove_file(&expired_path) {
+            annoy_warn!(
+                "[Service] Failed to remove expired index {}: {}",
+                expired_path.display(),
+                e
+            );
+        }
     }
-    // 트리 개수 증가: 40 -> 100
-    annoy.build(100);
-    annoy.save(PathBuf::from("../resources/channel_embeddings.ann"));
 
-    Ok(())
+    annoy_info!(
+        "[Service] Published {} {:?} index v{}: {} items, dimension {}, model {} (v{})",
+        kind,
+        index_type,
+        version,
+        built.items,
+        model.dimension,
+        model.id,
+        model.version
+    );
+    Ok(Some(PublishedIndex {
+        kind: kind.to_string(),
+        version,
+        model_id: model.id.to_string(),
+        model_version: model.version,
+        dimension: model.dimension,
+        items: built.items,
+        built_at,
+        versioned_path: versioned,
+        index_path: path.to_path_buf(),
+        manifest_path: manifest_path(path),
+    }))
 }
 
-async fn save_rss_annoy(embeddings: Vec<Embedding>) -> Result<(), OmniNewsError> {
-    if embeddings.is_empty() {
-        annoy_info!("[Service] No embeddings found for rss.");
-        return Ok(());
+/// 바뀐 임베딩만 반영할 이전 버전 인덱스와 그 버전의 워터마크.
+struct IndexBase {
+    path: PathBuf,
+    max_embedding_id: i32,
+    max_updated_at: Option<NaiveDateTime>,
+    source_rows: i64,
+}
+
+struct BuiltIndex {
+    items: usize,
+    rejected: usize,
+}
+
+/// 인덱스를 `tmp`에 저장함. 인덱스에 항목이 없으면 저장하지 않고 `None`을 반환함.
+/// `base`가 없으면 `max_embedding_id`까지의 임베딩을 모두 읽어 새로 만들고, 있으면 워터마크 이후
+/// 추가/갱신된 임베딩만 더한 뒤 삭제된 임베딩을 뺌. 워터마크와 같은 시점의 행만 넣도록
+/// 최대 ID까지만 읽으며, 이후 추가된 행은 다음 빌드에 포함됨.
+#[allow(clippy::too_many_arguments)]
+async fn build_index(
+    pool: &MySqlPool,
+    target: &IndexTarget,
+    model: &IndexModel<'_>,
+    index_type: VectorIndexType,
+    stats: &EmbeddingIndexStats,
+    max_embedding_id: i32,
+    base: Option<&IndexBase>,
+    tmp: &Path,
+) -> Result<Option<BuiltIndex>, OmniNewsError> {
+    let batch_size = get_env_or("ANNOY_LOAD_BATCH_SIZE", 1000_i64);
+    let builder = IndexBuilder::start(
+        index_type,
+        model.dimension,
+        base.map(|base| base.path.clone()),
+    );
+    let changed_since = base.map(|base| (base.max_embedding_id, base.max_updated_at));
+
+    let mut last_embedding_id = 0;
+    let mut items = 0;
+    let mut rejected = Vec::new();
+    let mut rejected_count = 0;
+    loop {
+        let embeddings = embedding_service::find_embedding_page(
+            pool,
+            &target.category,
+            model.version,
+            last_embedding_id,
+            max_embedding_id,
+            changed_since,
+            batch_size,
+        )
+        .await?;
+        let Some(page_max_id) = embeddings.iter().filter_map(|e| e.embedding_id).max() else {
+            break;
+        };
+        last_embedding_id = page_max_id;
+
+        let (vectors, page_rejected) = split_by_dimension(&embeddings, model.dimension);
+        items += vectors.len();
+        rejected_count += page_rejected.len();
+        rejected.extend(
+            page_rejected
+                .into_iter()
+                .take(10_usize.saturating_sub(rejected.len())),
+        );
+        builder.send(IndexOp::Add(vectors)).await?;
+    }
+
+    // 이전 버전 이후 삭제된 임베딩이 있으면 현재 ID 목록에 없는 항목을 뺌
+    if base.is_some_and(|base| base.source_rows + stats.new_rows > stats.row_count) {
+        let mut ids = HashSet::new();
+        let mut last_embedding_id = 0;
+        loop {
+            let page = embedding_service::find_embedding_ids(
+                pool,
+                &target.category,
+                model.version,
+                last_embedding_id,
+                batch_size,
+            )
+            .await?;
+            let Some(&page_max_id) = page.iter().max() else {
+                break;
+            };
+            last_embedding_id = page_max_id;
+            ids.extend(page.into_iter().filter(|&id| id <= max_embedding_id));
+        }
+        builder.send(IndexOp::Retain(ids)).await?;
     }
-    let embedding_dim = embeddings[0].embedding_value.as_ref().unwrap().len();
-    annoy_info!("[Service] Rss Item Embedding dimension: {}", embedding_dim);
 
-    let annoy = rannoy::Rannoy::new(384);
-    annoy.set_seed(123);
+    if rejected_count > 0 {
+        annoy_warn!(
+            "[Service] Rejected {} {} embeddings with mismatched dimension (expected {}), e.g. embedding_id {:?}",
+            rejected_count,
+            target.kind,
+            model.dimension,
+            rejected
+        );
+    }
 
-    for embedding in embeddings.iter() {
-        let decoded_embedding = decode_embedding(embedding.embedding_value.as_ref().unwrap());
-        annoy.add_item(embedding.embedding_id.unwrap(), decoded_embedding.as_ref());
+    let len = builder.save(tmp.to_path_buf()).await?;
+    // Annoy의 항목 수는 최대 ID 기준이므로 새로 만든 경우 실제로 넣은 개수를 사용함
+    let items = if base.is_some() { len } else { items };
+    if items == 0 {
+        let _ = fs::remove_file(tmp);
+        return Ok(None);
     }
-    // 트리 개수 증가
-    annoy.build(100);
-    annoy.save(PathBuf::from("../resources/rss_embeddings.ann"));
 
-    Ok(())
+    Ok(Some(BuiltIndex {
+        items,
+        rejected: rejected_count,
+    }))
 }
 
-async fn save_news_annoy(embeddings: Vec<Embedding>) -> Result<(), OmniNewsError> {
-    if embeddings.is_empty() {
-        annoy_info!("[Service] No embeddings found for news.");
-        return Ok(());
+enum IndexOp {
+    Add(Vec<(i32, Vec<f32>)>),
+    // 주어진 ID에 없는 항목을 삭제
+    Retain(HashSet<i32>),
+}
+
+/// Annoy 인덱스는 스레드 사이로 옮길 수 없으므로 인덱스는 blocking 스레드에서 만들고,
+/// 조회한 페이지를 채널로 넘김. 전체 테이블을 메모리에 올리지 않고 한 페이지씩 인덱스에 반영함.
+/// `save` 없이 drop되면 인덱스를 저장하지 않고 버림.
+struct IndexBuilder {
+    tx: mpsc::Sender<IndexOp>,
+    save_tx: oneshot::Sender<PathBuf>,
+    handle: JoinHandle<Result<usize, String>>,
+}
+
+impl IndexBuilder {
+    fn start(index_type: VectorIndexType, dimension: usize, base: Option<PathBuf>) -> Self {
+        let (tx, rx) = mpsc::channel::<IndexOp>(2);
+        let (save_tx, save_rx) = oneshot::channel::<PathBuf>();
+
+        let handle = task::spawn_blocking(move || {
+            let result = run_builder(index_type, dimension, base, rx, save_rx);
+            if let Err(e) = &result {
+                annoy_error!("[Service] Failed to build {:?} index: {}", index_type, e);
+            }
+            result
+        });
+
+        Self {
+            tx,
+            save_tx,
+            handle,
+        }
     }
-    let embedding_dim = embeddings[0].embedding_value.as_ref().unwrap().len();
-    annoy_info!("[Service] Embedding dimension: {}", embedding_dim);
 
-    let annoy = rannoy::Rannoy::new(384);
-    annoy.set_seed(123);
+    async fn send(&self, op: IndexOp) -> Result<(), OmniNewsError> {
+        self.tx
+            .send(op)
+            .await
+            .map_err(|_| OmniNewsError::Io(io::Error::other("index builder stopped")))
+    }
 
-    for embedding in embeddings.iter() {
-        let decoded_embedding = decode_embedding(embedding.embedding_value.as_ref().unwrap());
-        annoy.add_item(embedding.embedding_id.unwrap(), decoded_embedding.as_ref());
+    /// 인덱스를 `path`에 저장하고 항목 수를 반환함. 항목이 없으면 저장하지 않음.
+    async fn save(self, path: PathBuf) -> Result<usize, OmniNewsError> {
+        let Self {
+            tx,
+            save_tx,
+            handle,
+        } = self;
+        drop(tx);
+        let _ = save_tx.send(path);
+
+        handle
+            .await
+            .map_err(|e| OmniNewsError::Io(io::Error::other(e)))?
+            .map_err(|e| OmniNewsError::Io(io::Error::other(e)))
     }
-    // 트리 개수 증가
-    annoy.build(100);
-    annoy.save(PathBuf::from("../resources/news_embeddings.ann"));
+}
 
+fn run_builder(
+    index_type: VectorIndexType,
+    dimension: usize,
+    base: Option<PathBuf>,
+    mut rx: mpsc::Receiver<IndexOp>,
+    save_rx: oneshot::Receiver<PathBuf>,
+) -> Result<usize, String> {
+    let mut index = match &base {
+        Some(base) => index_type.load(base, dimension)?,
+        None => index_type.create(dimension),
+    };
+
+    while let Some(op) = rx.blocking_recv() {
+        match op {
+            IndexOp::Add(vectors) => {
+                for (embedding_id, vector) in vectors.iter() {
+                    index.add(*embedding_id, vector)?;
+                }
+            }
+            IndexOp::Retain(ids) => {
+                index.retain(&ids)?;
+            }
+        }
+    }
+    let Ok(path) = save_rx.blocking_recv() else {
+        return Ok(0);
+    };
+
+    if index.is_empty() {
+        return Ok(0);
+    }
+    index.save(&path)?;
+    File::open(&path)
+        .and_then(|file| file.sync_all())
+        .map_err(|e| e.to_string())?;
+    Ok(index.len())
+}
+
+/// 버전 파일을 하드 링크(안 되면 복사)로 임시 경로에 만든 뒤 게시 경로로 이름을 바꿈.
+/// 이름 변경은 원자적이므로 읽는 쪽은 이전 인덱스나 새 인덱스 중 하나만 봄.
+fn publish_file(versioned: &Path, path: &Path) -> Result<(), OmniNewsError> {
+    let tmp = tmp_path(path);
+    let _ = fs::remove_file(&tmp);
+    if fs::hard_link(versioned, &tmp).is_err() {
+        fs::copy(versioned, &tmp)?;
+        File::open(&tmp)?.sync_all()?;
+    }
+    fs::rename(&tmp, path)?;
+    sync_dir(path)
+}
+
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), OmniNewsError> {
+    let tmp = tmp_path(path);
+    let mut file = File::create(&tmp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)?;
+    sync_dir(path)
+}
+
+// 이름 변경이 디렉토리에 반영되도록 상위 디렉토리도 fsync함
+fn sync_dir(path: &Path) -> Result<(), OmniNewsError> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()?;
     Ok(())
 }
+
+fn index_dir() -> PathBuf {
+    PathBuf::from(env::var("ANNOY_INDEX_DIR").unwrap_or_else(|_| DEFAULT_INDEX_DIR.to_string()))
+}
+
+fn read_manifest(index_path: &Path) -> IndexManifest {
+    fs::read_to_string(manifest_path(index_path))
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+/// 차원이 맞는 벡터와, 차원이 달라 제외한 임베딩 ID를 나눔.
+fn split_by_dimension(
+    embeddings: &[Embedding],
+    dimension: usize,
+) -> (Vec<(i32, Vec<f32>)>, Vec<i32>) {
+    let mut vectors = Vec::with_capacity(embeddings.len());
+    let mut rejected = Vec::new();
+
+    for embedding in embeddings {
+        let Some(embedding_id) = embedding.embedding_id else {
+            continue;
+        };
+        let vector = decode_embedding(embedding.embedding_value.as_deref().unwrap_or_default());
+        if vector.len() == dimension {
+            vectors.push((embedding_id, vector));
+        } else {
+            rejected.push(embedding_id);
+        }
+    }
+    (vectors, rejected)
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+fn manifest_path(index_path: &Path) -> PathBuf {
+    with_suffix(index_path, ".manifest.json")
+}
+
+fn versioned_path(index_path: &Path, version: u64) -> PathBuf {
+    with_suffix(index_path, &format!(".v{}", version))
+}
+
+fn tmp_path(path: &Path) -> PathBuf {
+    with_suffix(path, ".tmp")
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+// 게시할 항목이 없으면 이전 인덱스가 삭제된 항목을 계속 가리키지 않도록 게시만 내림.
+// `ANNOY_KEEP_VERSIONS`로 보관 중인 버전은 롤백용으로 남겨 둠
+fn unpublish_index(path: &Path) {
+    let mut manifest = read_manifest(path);
+    if manifest.current.take().is_some() {
+        let contents = serde_json::to_string_pretty(&manifest).unwrap_or_default();
+        if let Err(e) = write_atomic(&manifest_path(path), contents.as_bytes()) {
+            annoy_warn!(
+                "[Service] Failed to clear current version of {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+
+    if path.exists() {
+        match fs::remove_file(path) {
+            Ok(_) => annoy_info!("[Service] Unpublished stale annoy index {}", path.display()),
+            Err(e) => annoy_warn!("[Service] Failed to remove {}: {}", path.display(), e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::embedding_util::encode_embedding;
+
+    fn embedding(embedding_id: i32, dimension: usize) -> Embedding {
+        Embedding {
+            embedding_id: Some(embedding_id),
+            embedding_value: Some(encode_embedding(&vec![0.1; dimension])),
+            channel_id: None,
+            rss_id: None,
+            news_id: Some(embedding_id),
+            embedding_source_rank: None,
+            embedding_model_id: Some("test-model".to_string()),
+            embedding_model_version: Some(1),
+            embedding_content_hash: None,
+        }
+    }
+
+    #[test]
+    fn rejects_vectors_with_mismatched_dimension() {
+        let embeddings = vec![embedding(1, 512), embedding(2, 384), embedding(3, 512)];
+        let (vectors, rejected) = split_by_dimension(&embeddings, 512);
+
+        assert_eq!(
+            vectors.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(rejected, vec![2]);
+    }
+
+    fn index_version(version: u64) -> IndexVersion {
+        IndexVersion {
+            version,
+            file: format!("news_embeddings.ann.v{}", version),
+            model_id: "test-model".to_string(),
+            model_version: 1,
+            dimension: 512,
+            items: 10,
+            rejected: 0,
+            built_at: "2025-10-15T00:00:00+00:00".to_string(),
+            source_rows: 10,
+            max_embedding_id: Some(10),
+            max_updated_at: None,
+        }
+    }
+
+    #[test]
+    fn stores_manifest_and_versions_next_to_index() {
+        let path = Path::new("../resources/news_embeddings.ann");
+        assert_eq!(
+            manifest_path(path),
+            PathBuf::from("../resources/news_embeddings.ann.manifest.json")
+        );
+        assert_eq!(
+            versioned_path(path, 12),
+            PathBuf::from("../resources/news_embeddings.ann.v12")
+        );
+    }
+
+    #[test]
+    fn keeps_previous_versions_for_rollback() {
+        let mut manifest = IndexManifest::default();
+        assert_eq!(manifest.next_version(), 1);
+
+        let mut expired = Vec::new();
+        for version in 1..=5 {
+            expired.extend(manifest.publish(index_version(version), 2));
+        }
+
+        assert_eq!(manifest.current, Some(5));
+        assert_eq!(
+            manifest
+                .versions
+                .iter()
+                .map(|v| v.version)
+                .collect::<Vec<_>>(),
+            vec![5, 4, 3]
+        );
+        assert_eq!(
+            expired.iter().map(|v| v.version).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(manifest.next_version(), 6);
+    }
+
+    fn stats(row_count: i64, new_rows: i64, updated_rows: i64) -> EmbeddingIndexStats {
+        EmbeddingIndexStats {
+            row_count,
+            max_embedding_id: Some(10 + new_rows as i32),
+            max_updated_at: None,
+            new_rows,
+            updated_rows,
+        }
+    }
+
+    #[test]
+    fn rebuilds_only_when_changes_reach_threshold() {
+        let model = IndexModel {
+            id: "test-model",
+            version: 1,
+            dimension: 512,
+        };
+        let current = index_version(1);
+        let built_at = DateTime::parse_from_rfc3339(&current.built_at)
+            .unwrap()
+            .with_timezone(&Utc);
+        let soon = built_at + Duration::minutes(10);
+        let reason =
+            |current: Option<&IndexVersion>, stats: &EmbeddingIndexStats, now: DateTime<Utc>| {
+                rebuild_reason(current, stats, &model, 5, Duration::minutes(60), now)
+            };
+
+        assert_eq!(
+            reason(None, &stats(10, 0, 0), soon),
+            Some(RebuildReason::Missing)
+        );
+        assert_eq!(reason(Some(&current), &stats(10, 0, 0), soon), None);
+        // 추가 2, 갱신 1, 삭제 2 (10 + 2 - 10)
+        assert_eq!(
+            reason(Some(&current), &stats(10, 2, 1), soon),
+            Some(RebuildReason::Changed(5))
+        );
+        assert_eq!(reason(Some(&current), &stats(11, 1, 0), soon), None);
+        // 임계값 미만이어도 오래되면 다시 만듦
+        assert_eq!(
+            reason(
+                Some(&current),
+                &stats(11, 1, 0),
+                built_at + Duration::minutes(60)
+            ),
+            Some(RebuildReason::Expired(1))
+        );
+
+        let other_model = IndexVersion {
+            model_version: 2,
+            ..index_version(1)
+        };
+        assert_eq!(
+            reason(Some(&other_model), &stats(10, 0, 0), soon),
+            Some(RebuildReason::ModelChanged)
+        );
+    }
+
+    #[test]
+    fn publishes_by_renaming_over_previous_index() {
+        let dir = std::env::temp_dir().join(format!("omninews-annoy-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("news_embeddings.ann");
+
+        for (version, contents) in [(1, "first"), (2, "second")] {
+            let versioned = versioned_path(&path, version);
+            write_atomic(&versioned, contents.as_bytes()).unwrap();
+            publish_file(&versioned, &path).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        // 이전 버전은 롤백용으로 남아 있음
+        assert_eq!(
+            fs::read_to_string(versioned_path(&path, 1)).unwrap(),
+            "first"
+        );
+        assert!(!tmp_path(&path).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,246 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    // "3분 전", "3분전", "3 minutes ago", "3 mins ago"
+    static ref RELATIVE_RE: Regex = Regex::new(
+        r"^(\d+)\s*(초|분|시간|일|주|seconds?|secs?|minutes?|mins?|hours?|hrs?|days?|weeks?)\s*(전|ago)$"
+    )
+    .unwrap();
+    // "2025.10.15. 오후 3:12", "2025-10-15 15:12:00", "2025/10/15", "2025.10.15."
+    static ref ABSOLUTE_RE: Regex = Regex::new(
+        r"^(\d{4})\s*[./-]\s*(\d{1,2})\s*[./-]\s*(\d{1,2})\.?(?:\s*(오전|오후|am|pm))?(?:\s*(\d{1,2}):(\d{2})(?::(\d{2}))?)?(?:\s*(오전|오후|am|pm))?$"
+    )
+    .unwrap();
+}
+
+fn kst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).unwrap()
+}
+
+/// 뉴스 목록/메타에 나오는 발행 시각을 KST 기준 시각으로 변환함.
+/// 한국어/영어 상대 시간, "2025.10.15. 오후 3:12" 같은 절대 시간, ISO-8601, RFC 2822를 지원함.
+pub fn parse_news_date(text: &str) -> Option<NaiveDateTime> {
+    parse_news_date_at(text, Utc::now().with_timezone(&kst()).naive_local())
+}
+
+/// `now`(KST)를 기준으로 상대 시간을 계산함.
+pub fn parse_news_date_at(text: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    parse_relative(text, now)
+        .or_else(|| parse_with_offset(text))
+        .or_else(|| parse_absolute(text))
+}
+
+fn parse_relative(text: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let lower = text.to_lowercase();
+    let compact = lower.split_whitespace().collect::<String>();
+
+    match compact.as_str() {
+        "방금" | "방금전" | "justnow" | "now" => return Some(now),
+        "어제" | "yesterday" => return now.checked_sub_signed(Duration::days(1)),
+        "그제" | "그저께" => return now.checked_sub_signed(Duration::days(2)),
+        _ => (),
+    }
+
+    let captures = RELATIVE_RE.captures(&lower)?;
+    let value = captures[1].parse::<i64>().ok()?;
+    let unit = &captures[2];
+
+    // i64로는 파싱되지만 Duration 범위를 넘는 값은 패닉 대신 None으로 처리함
+    let duration = if unit == "초" || unit.starts_with("sec") {
+        Duration::try_seconds(value)
+    } else if unit == "분" || unit.starts_with("min") {
+        Duration::try_minutes(value)
+    } else if unit == "시간" || unit.starts_with("h") {
+        Duration::try_hours(value)
+    } else if unit == "일" || unit.starts_with("day") {
+        Duration::try_days(value)
+    } else {
+        Duration::try_weeks(value)
+    }?;
+
+    now.checked_sub_signed(duration)
+}
+
+// 시간대 정보가 있는 형식 (ISO-8601, RFC 2822)
+fn parse_with_offset(text: &str) -> Option<NaiveDateTime> {
+    DateTime::parse_from_rfc3339(text)
+        .or_else(|_| DateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%z"))
+        .or_else(|_| DateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .or_else(|_| DateTime::parse_from_rfc2822(text))
+        .ok()
+        .map(|dt| dt.with_timezone(&kst()).naive_local())
+}
+
+// 시간대 정보가 없는 형식은 KST로 간주함
+fn parse_absolute(text: &str) -> Option<NaiveDateTime> {
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M:%S%.f"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(text, format) {
+            return Some(dt);
+        }
+    }
+
+    let lower = text.to_lowercase();
+    let captures = ABSOLUTE_RE.captures(&lower)?;
+    let number = |i: usize| captures.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+
+    let date = NaiveDate::from_ymd_opt(captures[1].parse().ok()?, number(2)?, number(3)?)?;
+    let meridiem = captures
+        .get(4)
+        .or_else(|| captures.get(8))
+        .map(|m| m.as_str());
+
+    let mut hour = number(5).unwrap_or(0);
+    match meridiem {
+        Some("오후" | "pm") if hour < 12 => hour += 12,
+        Some("오전" | "am") if hour == 12 => hour = 0,
+        _ => (),
+    }
+
+    date.and_hms_opt(hour, number(6).unwrap_or(0), number(7).unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_korean_and_english_relative_times() {
+        let now = at(2025, 10, 15, 12, 0, 0);
+
+        assert_eq!(
+            parse_news_date_at("3분전", now),
+            Some(at(2025, 10, 15, 11, 57, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("3분 전", now),
+            Some(at(2025, 10, 15, 11, 57, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2시간전", now),
+            Some(at(2025, 10, 15, 10, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("3일 전", now),
+            Some(at(2025, 10, 12, 12, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("1주 전", now),
+            Some(at(2025, 10, 8, 12, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("30초 전", now),
+            Some(at(2025, 10, 15, 11, 59, 30))
+        );
+        assert_eq!(parse_news_date_at("방금 전", now), Some(now));
+        assert_eq!(
+            parse_news_date_at("어제", now),
+            Some(at(2025, 10, 14, 12, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("그저께", now),
+            Some(at(2025, 10, 13, 12, 0, 0))
+        );
+
+        assert_eq!(
+            parse_news_date_at("5 mins ago", now),
+            Some(at(2025, 10, 15, 11, 55, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("1 hour ago", now),
+            Some(at(2025, 10, 15, 11, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2 days ago", now),
+            Some(at(2025, 10, 13, 12, 0, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("Yesterday", now),
+            Some(at(2025, 10, 14, 12, 0, 0))
+        );
+    }
+
+    #[test]
+    fn parses_absolute_korean_formats() {
+        let now = at(2025, 10, 15, 12, 0, 0);
+
+        assert_eq!(
+            parse_news_date_at("2025.10.15. 오후 3:12", now),
+            Some(at(2025, 10, 15, 15, 12, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2025.10.15. 오전 12:05", now),
+            Some(at(2025, 10, 15, 0, 5, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2025-10-15 09:30:15", now),
+            Some(at(2025, 10, 15, 9, 30, 15))
+        );
+        assert_eq!(
+            parse_news_date_at("2025/10/15 3:12 PM", now),
+            Some(at(2025, 10, 15, 15, 12, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2025.10.15.", now),
+            Some(at(2025, 10, 15, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn converts_offset_formats_to_kst() {
+        let now = at(2025, 10, 15, 12, 0, 0);
+
+        assert_eq!(
+            parse_news_date_at("2025-10-15T01:12:00Z", now),
+            Some(at(2025, 10, 15, 10, 12, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2025-10-15T14:57:00+09:00", now),
+            Some(at(2025, 10, 15, 14, 57, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2025-10-15T14:57:00+0900", now),
+            Some(at(2025, 10, 15, 14, 57, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("Wed, 15 Oct 2025 01:12:00 GMT", now),
+            Some(at(2025, 10, 15, 10, 12, 0))
+        );
+        assert_eq!(
+            parse_news_date_at("2025-10-15T14:57:00", now),
+            Some(at(2025, 10, 15, 14, 57, 0))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_input_without_panicking() {
+        let now = at(2025, 10, 15, 12, 0, 0);
+
+        for text in [
+            "",
+            "분전",
+            "시간전",
+            "속보",
+            "2025.13.40.",
+            "99999999999999999999분 전",
+            "99999999999999999초 전",
+            "9999999999999999시간 전",
+            "999999999999999 weeks ago",
+        ] {
+            assert_eq!(parse_news_date_at(text, now), None, "{text}");
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use rust_bert::pipelines::sentence_embeddings::{
+    SentenceEmbeddingsBuilder, SentenceEmbeddingsModel, SentenceEmbeddingsModelType,
+};
+
+use super::Embedder;
+
+pub struct RustBertEmbedder {
+    model: SentenceEmbeddingsModel,
+}
+
+impl RustBertEmbedder {
+    /// `model_dir`이 있으면 해당 디렉토리의 변환된 모델(`rust_model.ot`, 설정/토크나이저 파일)을 읽고,
+    /// 없으면 Hugging Face에서 DistiluseBaseMultilingualCased를 내려받음.
+    pub fn load(model_dir: Option<&Path>) -> Result<Self, String> {
+        let model = match model_dir {
+            Some(dir) => SentenceEmbeddingsBuilder::local(dir).create_model(),
+            None => SentenceEmbeddingsBuilder::remote(
+                SentenceEmbeddingsModelType::DistiluseBaseMultilingualCased,
+            )
+            .create_model(),
+        }
+        .map_err(|e| e.to_string())?;
+
+        Ok(Self { model })
+    }
+}
+
+impl Embedder for RustBertEmbedder {
+    fn encode(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String> {
+        self.model.encode(texts).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,73 @@
+use super::Embedder;
+
+/// 토큰을 해시해 차원에 더하는 결정적 임베더 (feature hashing).
+/// 모델 없이 같은 입력에 항상 같은 벡터를 만들고, 토큰을 공유하는 문장끼리 유사도가 높아 테스트에 사용함.
+pub struct HashEmbedder {
+    dimension: usize,
+}
+
+impl HashEmbedder {
+    pub fn new(dimension: usize) -> Self {
+        Self { dimension }
+    }
+
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let mut embedding = vec![0.0; self.dimension];
+        for token in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| !token.is_empty())
+        {
+            let hash = fnv1a(token.to_lowercase().as_bytes());
+            let index = (hash % self.dimension as u64) as usize;
+            // 상위 비트로 부호를 정해 충돌한 토큰끼리 상쇄되도록 함
+            embedding[index] += if hash >> 63 == 0 { 1.0 } else { -1.0 };
+        }
+        embedding
+    }
+}
+
+impl Embedder for HashEmbedder {
+    fn encode(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String> {
+        Ok(texts.iter().map(|text| self.embed(text)).collect())
+    }
+}
+
+// 실행 환경과 관계없이 같은 값을 내는 FNV-1a 64비트 해시
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::embedding_util::cosine_similarity;
+
+    #[test]
+    fn embeds_deterministically() {
+        let embedder = HashEmbedder::new(64);
+        let first = embedder.encode(&["국회 예산안 심사 착수"]).unwrap();
+        let second = embedder.encode(&["국회 예산안 심사 착수"]).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first[0].len(), 64);
+    }
+
+    #[test]
+    fn shares_similarity_between_overlapping_texts() {
+        let embedder = HashEmbedder::new(512);
+        let embeddings = embedder
+            .encode(&[
+                "국회 예산안 심사 착수",
+                "국회 예산안 심사 내일 시작",
+                "프로야구 개막전 매진",
+            ])
+            .unwrap();
+
+        assert!(
+            cosine_similarity(&embeddings[0], &embeddings[1])
+                > cosine_similarity(&embeddings[0], &embeddings[2])
+        );
+    }
+}
@@ -0,0 +1,135 @@
+pub mod bert;
+pub mod hash;
+#[cfg(feature = "onnx")]
+pub mod onnx;
+
+use std::{env, path::PathBuf};
+
+use crate::{annoy_warn, config::env::get_env_or};
+
+use self::{bert::RustBertEmbedder, hash::HashEmbedder};
+
+/// 기본 임베딩 모델 ID. 임베딩 행과 Annoy 인덱스 메타데이터에 기록됨.
+pub const DEFAULT_MODEL_ID: &str = "distiluse-base-multilingual-cased";
+
+/// 문장을 벡터로 바꾸는 백엔드. 임베딩 워커 스레드 안에서만 생성/사용됨.
+pub trait Embedder {
+    /// 입력 순서대로 정규화 전 벡터를 반환함.
+    fn encode(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedderBackend {
+    RustBert,
+    Onnx,
+    // 모델 없이 토큰 해시로 벡터를 만드는 테스트용 백엔드
+    Hash,
+}
+
+impl EmbedderBackend {
+    fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "rust_bert" | "rust-bert" => Some(Self::RustBert),
+            "onnx" => Some(Self::Onnx),
+            "hash" => Some(Self::Hash),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmbedderConfig {
+    pub backend: EmbedderBackend,
+    pub model_id: String,
+    // 설정하면 다운로드 없이 이 디렉토리에서 모델을 읽음
+    pub model_dir: Option<PathBuf>,
+    pub hash_dimension: usize,
+}
+
+impl EmbedderConfig {
+    /// `EMBEDDING_BACKEND`(`rust_bert`, `onnx`, `hash`), `EMBEDDING_MODEL_DIR`, `EMBEDDING_MODEL_ID`로 구성함.
+    pub fn from_env() -> Self {
+        let backend = env::var("EMBEDDING_BACKEND")
+            .ok()
+            .map(|name| {
+                EmbedderBackend::parse(&name).unwrap_or_else(|| {
+                    annoy_warn!("[Embedder] Unknown backend {}, use rust_bert", name);
+                    EmbedderBackend::RustBert
+                })
+            })
+            .unwrap_or(EmbedderBackend::RustBert);
+        let model_dir = env::var("EMBEDDING_MODEL_DIR")
+            .ok()
+            .filter(|dir| !dir.trim().is_empty())
+            .map(PathBuf::from);
+        let hash_dimension = get_env_or("EMBEDDING_HASH_DIMENSION", 512_usize).max(1);
+
+        let model_id = env::var("EMBEDDING_MODEL_ID")
+            .ok()
+            .filter(|id| !id.trim().is_empty())
+            .unwrap_or_else(|| match backend {
+                EmbedderBackend::Hash => format!("hash-{}", hash_dimension),
+                _ => DEFAULT_MODEL_ID.to_string(),
+            });
+
+        Self {
+            backend,
+            model_id,
+            model_dir,
+            hash_dimension,
+        }
+    }
+
+    pub fn hash(dimension: usize) -> Self {
+        Self {
+            backend: EmbedderBackend::Hash,
+            model_id: format!("hash-{}", dimension),
+            model_dir: None,
+            hash_dimension: dimension,
+        }
+    }
+
+    /// 설정된 백엔드를 생성함. 모델을 읽지 못하면 오류를 반환하며 패닉하지 않음.
+    pub fn create(&self) -> Result<Box<dyn Embedder>, String> {
+        match self.backend {
+            EmbedderBackend::RustBert => {
+                Ok(Box::new(RustBertEmbedder::load(self.model_dir.as_deref())?))
+            }
+            EmbedderBackend::Onnx => create_onnx(self),
+            EmbedderBackend::Hash => Ok(Box::new(HashEmbedder::new(self.hash_dimension))),
+        }
+    }
+}
+
+#[cfg(feature = "onnx")]
+fn create_onnx(config: &EmbedderConfig) -> Result<Box<dyn Embedder>, String> {
+    let dir = config
+        .model_dir
+        .as_deref()
+        .ok_or("EMBEDDING_MODEL_DIR is required for the onnx backend")?;
+    Ok(Box::new(onnx::OnnxEmbedder::load(dir)?))
+}
+
+#[cfg(not(feature = "onnx"))]
+fn create_onnx(_config: &EmbedderConfig) -> Result<Box<dyn Embedder>, String> {
+    Err("onnx backend requires building with `--features onnx`".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_backend_names() {
+        assert_eq!(
+            EmbedderBackend::parse("rust-bert"),
+            Some(EmbedderBackend::RustBert)
+        );
+        assert_eq!(
+            EmbedderBackend::parse(" ONNX "),
+            Some(EmbedderBackend::Onnx)
+        );
+        assert_eq!(EmbedderBackend::parse("hash"), Some(EmbedderBackend::Hash));
+        assert_eq!(EmbedderBackend::parse("openai"), None);
+    }
+}
@@ -0,0 +1,156 @@
+use std::path::Path;
+
+use ort::{session::Session, value::Tensor};
+use tokenizers::{PaddingParams, Tokenizer, TruncationParams};
+
+use super::Embedder;
+
+// 토큰 길이 제한 (rust-bert 문장 임베딩 모델의 기본값과 같음)
+const MAX_SEQUENCE_LENGTH: usize = 128;
+
+/// ONNX Runtime으로 실행하는 문장 임베딩 모델.
+/// `model_dir`에 `model.onnx`와 `tokenizer.json`이 있어야 함.
+/// 모델 출력에 `sentence_embedding`이 있으면 그대로 사용하고,
+/// 없으면 첫 번째 출력(토큰 임베딩)을 attention mask로 평균 풀링함.
+pub struct OnnxEmbedder {
+    session: Session,
+    tokenizer: Tokenizer,
+    token_type_ids: bool,
+}
+
+impl OnnxEmbedder {
+    pub fn load(model_dir: &Path) -> Result<Self, String> {
+        let session = Session::builder()
+            .and_then(|builder| builder.commit_from_file(model_dir.join("model.onnx")))
+            .map_err(|e| e.to_string())?;
+
+        let mut tokenizer =
+            Tokenizer::from_file(model_dir.join("tokenizer.json")).map_err(|e| e.to_string())?;
+        tokenizer.with_padding(Some(PaddingParams::default()));
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: MAX_SEQUENCE_LENGTH,
+                ..Default::default()
+            }))
+            .map_err(|e| e.to_string())?;
+
+        let token_type_ids = session
+            .inputs
+            .iter()
+            .any(|input| input.name == "token_type_ids");
+
+        Ok(Self {
+            session,
+            tokenizer,
+            token_type_ids,
+        })
+    }
+}
+
+impl Embedder for OnnxEmbedder {
+    fn encode(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String> {
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts.to_vec(), true)
+            .map_err(|e| e.to_string())?;
+        let batch = encodings.len();
+        let length = encodings.first().map(|e| e.len()).unwrap_or_default();
+        if batch == 0 || length == 0 {
+            return Ok(vec![Vec::new(); batch]);
+        }
+
+        let collect = |ids: fn(&tokenizers::Encoding) -> &[u32]| {
+            encodings
+                .iter()
+                .flat_map(|encoding| ids(encoding).iter().map(|&id| id as i64))
+                .collect::<Vec<_>>()
+        };
+        let input_ids = collect(tokenizers::Encoding::get_ids);
+        let attention_mask = collect(tokenizers::Encoding::get_attention_mask);
+        let token_type_ids = collect(tokenizers::Encoding::get_type_ids);
+
+        let tensor = |values: Vec<i64>| {
+            Tensor::from_array(([batch, length], values))
+                .map(|tensor| tensor.into_dyn())
+                .map_err(|e| e.to_string())
+        };
+        let mut inputs = vec![
+            ("input_ids", tensor(input_ids)?),
+            ("attention_mask", tensor(attention_mask.clone())?),
+        ];
+        if self.token_type_ids {
+            inputs.push(("token_type_ids", tensor(token_type_ids)?));
+        }
+
+        let outputs = self.session.run(inputs).map_err(|e| e.to_string())?;
+
+        if let Some(sentence_embedding) = outputs.get("sentence_embedding") {
+            let (shape, values) = sentence_embedding
+                .try_extract_raw_tensor::<f32>()
+                .map_err(|e| e.to_string())?;
+            let dimension = *shape.last().ok_or("empty output shape")? as usize;
+            return Ok(values.chunks(dimension).map(<[f32]>::to_vec).collect());
+        }
+
+        let (shape, values) = outputs[0]
+            .try_extract_raw_tensor::<f32>()
+            .map_err(|e| e.to_string())?;
+        if shape.len() != 3 {
+            return Err(format!("Unexpected output shape {:?}", shape));
+        }
+        Ok(mean_pool(
+            values,
+            &attention_mask,
+            length,
+            shape[2] as usize,
+        ))
+    }
+}
+
+/// `[batch, length, dimension]` 토큰 임베딩을 attention mask가 1인 토큰만 평균냄.
+fn mean_pool(
+    values: &[f32],
+    attention_mask: &[i64],
+    length: usize,
+    dimension: usize,
+) -> Vec<Vec<f32>> {
+    values
+        .chunks(length * dimension)
+        .zip(attention_mask.chunks(length))
+        .map(|(tokens, mask)| {
+            let mut embedding = vec![0.0; dimension];
+            let mut count = 0.0;
+            for (token, _) in tokens
+                .chunks(dimension)
+                .zip(mask)
+                .filter(|(_, mask)| **mask > 0)
+            {
+                for (sum, value) in embedding.iter_mut().zip(token) {
+                    *sum += value;
+                }
+                count += 1.0;
+            }
+            if count > 0.0 {
+                embedding.iter_mut().for_each(|value| *value /= count);
+            }
+            embedding
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mean_pools_only_attended_tokens() {
+        // batch 2, length 2, dimension 2. 두 번째 문장의 두 번째 토큰은 패딩
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 100.0, 100.0];
+        let mask = [1, 1, 1, 0];
+
+        assert_eq!(
+            mean_pool(&values, &mask, 2, 2),
+            vec![vec![2.0, 3.0], vec![5.0, 6.0]]
+        );
+    }
+}
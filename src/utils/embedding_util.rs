@@ -1,52 +1,103 @@
 use std::{
-    sync::{mpsc, Arc, Mutex},
+    sync::{mpsc, Arc, Mutex, OnceLock},
     thread,
+    time::{Duration, Instant},
 };
 
-use rust_bert::pipelines::sentence_embeddings::{
-    SentenceEmbeddingsBuilder, SentenceEmbeddingsModelType,
+use crate::{
+    annoy_error, annoy_info, config::env::get_env_or, model::error::OmniNewsError,
+    rss_fetch_and_notification_error, utils::embedder::EmbedderConfig,
 };
 
-use crate::{annoy_info, model::error::OmniNewsError, rss_fetch_and_notification_error};
+type EmbeddingResponse = Result<Vec<Vec<f32>>, String>;
 
+/// 워커 스레드로 보내는 요청. 한 요청에 여러 문장을 담을 수 있음.
 struct EmbeddingRequest {
-    text: String,
-    response_tx: mpsc::Sender<Vec<f32>>,
+    texts: Vec<String>,
+    response_tx: mpsc::Sender<EmbeddingResponse>,
 }
 
 #[derive(Clone)]
 pub struct EmbeddingService {
     request_tx: Arc<Mutex<mpsc::Sender<EmbeddingRequest>>>,
+    // 모델 초기화 후 실제 출력 벡터 길이로 설정됨
+    dimension: Arc<OnceLock<usize>>,
+    // 임베딩 행과 Annoy 인덱스 메타데이터에 기록되는 모델 ID
+    model_id: Arc<str>,
+    // 모델을 바꿀 때 올리는 버전. 버전이 바뀌면 기존 임베딩을 백그라운드에서 다시 만듦.
+    model_version: i32,
 }
 
 impl EmbeddingService {
     pub fn new() -> Self {
+        Self::with_config(
+            EmbedderConfig::from_env(),
+            get_env_or("EMBEDDING_MODEL_VERSION", 1),
+        )
+    }
+
+    pub fn with_config(config: EmbedderConfig, model_version: i32) -> Self {
         // 요청 채널 생성
         let (request_tx, request_rx) = mpsc::channel::<EmbeddingRequest>();
+        let dimension = Arc::new(OnceLock::new());
+        let worker_dimension = dimension.clone();
+        let model_id = Arc::<str>::from(config.model_id.as_str());
+        // 대기 중인 요청을 최대 `batch_size`문장, `max_wait`까지 모아 한 번에 인코딩함
+        let batch_size = get_env_or("EMBEDDING_BATCH_SIZE", 32_usize).max(1);
+        let max_wait = Duration::from_millis(get_env_or("EMBEDDING_BATCH_MAX_WAIT_MS", 10_u64));
 
         thread::spawn(move || {
-            annoy_info!("[Worker Thread] Initializing worker thread module");
+            annoy_info!(
+                "[Worker Thread] Initializing worker thread module ({:?})",
+                config.backend
+            );
 
-            // TOO 다국어 지원 모델로 변경
-            let model = SentenceEmbeddingsBuilder::remote(
-                SentenceEmbeddingsModelType::DistiluseBaseMultilingualCased,
-            )
-            .create_model()
-            .expect("[Worker Thread] Error while initalizing model");
+            let embedder = match config.create() {
+                Ok(embedder) => embedder,
+                Err(e) => {
+                    // 모델을 읽지 못해도 스케줄러는 계속 동작하고, 임베딩 요청만 실패함
+                    annoy_error!(
+                        "[Worker Thread] Failed to initialize embedding model: {}",
+                        e
+                    );
+                    while let Ok(request) = request_rx.recv() {
+                        let _ = request
+                            .response_tx
+                            .send(Err("Embedding model is not loaded".to_string()));
+                    }
+                    return;
+                }
+            };
 
-            annoy_info!("[Worker Thread] Worker thread initialized");
+            // 모델마다 차원이 다르므로 (DistiluseBaseMultilingualCased는 512) 실제 출력으로 확인함
+            match embedder.encode(&["dimension probe"]) {
+                Ok(embeddings) if !embeddings.is_empty() => {
+                    let _ = worker_dimension.set(embeddings[0].len());
+                }
+                _ => annoy_error!("[Worker Thread] Failed to read embedding dimension"),
+            }
+
+            annoy_info!(
+                "[Worker Thread] Worker thread initialized (model: {}, dimension: {:?})",
+                config.model_id,
+                worker_dimension.get()
+            );
 
             // 요청 대기
-            while let Ok(request) = request_rx.recv() {
+            while let Some(batch) = collect_batch(&request_rx, batch_size, max_wait) {
+                let texts = batch
+                    .iter()
+                    .flat_map(|request| request.texts.iter().map(String::as_str))
+                    .collect::<Vec<_>>();
+
                 // 임베딩 생성
-                match model.encode(&[request.text]) {
-                    Ok(embeddings) if !embeddings.is_empty() => {
-                        let _ = request.response_tx.send(embeddings[0].clone());
-                    }
-                    _ => {
-                        let _ = request.response_tx.send(Vec::new());
-                    }
-                }
+                let embeddings = texts
+                    .chunks(batch_size)
+                    .map(|chunk| embedder.encode(chunk))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|chunks| chunks.into_iter().flatten().collect::<Vec<_>>());
+
+                respond(batch, embeddings);
             }
 
             annoy_info!("[Worker Thread] worker thread terminated");
@@ -54,16 +105,60 @@ impl EmbeddingService {
 
         Self {
             request_tx: Arc::new(Mutex::new(request_tx)),
+            dimension,
+            model_id,
+            model_version,
+        }
+    }
+
+    /// 현재 모델의 임베딩 차원. 모델 초기화 전에는 `None`.
+    pub fn dimension(&self) -> Option<usize> {
+        self.dimension.get().copied()
+    }
+
+    pub fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    pub fn model_version(&self) -> i32 {
+        self.model_version
+    }
+
+    /// 여러 문장의 정규화된 임베딩을 입력 순서대로 반환함.
+    /// 백필이나 재임베딩처럼 문장이 많을 때 한 요청으로 보내 워커의 배치 인코딩을 활용함.
+    pub async fn embed_many(&self, sentences: Vec<String>) -> Result<Vec<Vec<f32>>, OmniNewsError> {
+        if sentences.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let service = self.clone();
+        // Generate Embeddings
+        let embeddings = tokio::task::spawn_blocking(move || service.embed_texts(sentences))
+            .await
+            .map_err(|_| OmniNewsError::Embedding)?;
+
+        match embeddings {
+            Ok(mut res) => {
+                res.iter_mut().for_each(|embedding| normalize(embedding));
+                Ok(res)
+            }
+            Err(e) => {
+                rss_fetch_and_notification_error!(
+                    "[Embedding Service] Failed to generate embedding: {}",
+                    e
+                );
+                Err(OmniNewsError::Embedding)
+            }
         }
     }
 
     // 임베딩 생성 요청 메서드
-    fn embed_text(&self, text: String) -> Result<Vec<f32>, String> {
+    fn embed_texts(&self, texts: Vec<String>) -> EmbeddingResponse {
         // 응답용 채널 생성
         let (response_tx, response_rx) = mpsc::channel();
 
         // 요청 생성 및 전송
-        let request = EmbeddingRequest { text, response_tx };
+        let request = EmbeddingRequest { texts, response_tx };
 
         // 요청 전송
         self.request_tx
@@ -75,41 +170,92 @@ impl EmbeddingService {
         // 응답 대기
         response_rx
             .recv()
-            .map_err(|_| "Failed to recieved from worker thread ".to_string())
+            .map_err(|_| "Failed to recieved from worker thread ".to_string())?
     }
 }
 
-pub async fn embedding_sentence(
-    embedding_service: &EmbeddingService,
-    sentence: String,
-) -> Result<Vec<f32>, OmniNewsError> {
-    let service = embedding_service.clone();
-    // Generate Embeddings
-    let embedding = tokio::task::spawn_blocking(move || service.embed_text(sentence))
-        .await
-        .map_err(|_| OmniNewsError::Embedding)?;
-
-    match embedding {
-        Ok(mut res) => {
-            // 벡터 정규화
-            let norm: f32 = res.iter().map(|x| x * x).sum::<f32>().sqrt();
-            if norm > 0.0 {
-                for x in &mut res {
-                    *x /= norm;
-                }
+/// 첫 요청이 올 때까지 기다린 뒤, 문장 수가 `max_size`에 이르거나 `max_wait`이 지날 때까지
+/// 뒤따르는 요청을 모음. 요청 채널이 닫히면 `None`.
+fn collect_batch(
+    request_rx: &mpsc::Receiver<EmbeddingRequest>,
+    max_size: usize,
+    max_wait: Duration,
+) -> Option<Vec<EmbeddingRequest>> {
+    let first = request_rx.recv().ok()?;
+    let deadline = Instant::now() + max_wait;
+    let mut size = first.texts.len();
+    let mut batch = vec![first];
+
+    while size < max_size {
+        let Some(timeout) = deadline.checked_duration_since(Instant::now()) else {
+            break;
+        };
+        match request_rx.recv_timeout(timeout) {
+            Ok(request) => {
+                size += request.texts.len();
+                batch.push(request);
+            }
+            Err(_) => break,
+        }
+    }
+    Some(batch)
+}
+
+/// 배치 인코딩 결과를 요청별 문장 수만큼 나눠 돌려줌. 실패하면 배치의 모든 요청에 오류를 보냄.
+fn respond(batch: Vec<EmbeddingRequest>, embeddings: Result<Vec<Vec<f32>>, String>) {
+    let total = batch
+        .iter()
+        .map(|request| request.texts.len())
+        .sum::<usize>();
+    let embeddings = embeddings.and_then(|embeddings| {
+        if embeddings.len() == total {
+            Ok(embeddings)
+        } else {
+            Err(format!(
+                "Expected {} embeddings, got {}",
+                total,
+                embeddings.len()
+            ))
+        }
+    });
+
+    match embeddings {
+        Ok(embeddings) => {
+            let mut embeddings = embeddings.into_iter();
+            for request in batch {
+                let part = embeddings.by_ref().take(request.texts.len()).collect();
+                let _ = request.response_tx.send(Ok(part));
             }
-            Ok(res)
         }
         Err(e) => {
-            rss_fetch_and_notification_error!(
-                "[Embedding Service] Failed to generate embedding: {}",
-                e
-            );
-            Err(OmniNewsError::Embedding)
+            for request in batch {
+                let _ = request.response_tx.send(Err(e.clone()));
+            }
+        }
+    }
+}
+
+// 벡터 정규화
+fn normalize(embedding: &mut [f32]) {
+    let norm: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for x in embedding.iter_mut() {
+            *x /= norm;
         }
     }
 }
 
+pub async fn embedding_sentence(
+    embedding_service: &EmbeddingService,
+    sentence: String,
+) -> Result<Vec<f32>, OmniNewsError> {
+    embedding_service
+        .embed_many(vec![sentence])
+        .await?
+        .pop()
+        .ok_or(OmniNewsError::Embedding)
+}
+
 pub fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(embedding.len() * 4);
     for &value in embedding {
@@ -127,3 +273,105 @@ pub fn decode_embedding(bytes: &[u8]) -> Vec<f32> {
     }
     embedding
 }
+
+/// 두 벡터의 코사인 유사도. 길이가 다르거나 영벡터면 0을 반환함.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(texts: &[&str]) -> (EmbeddingRequest, mpsc::Receiver<EmbeddingResponse>) {
+        let (response_tx, response_rx) = mpsc::channel();
+        let request = EmbeddingRequest {
+            texts: texts.iter().map(|text| text.to_string()).collect(),
+            response_tx,
+        };
+        (request, response_rx)
+    }
+
+    #[test]
+    fn collects_pending_requests_up_to_batch_size() {
+        let (request_tx, request_rx) = mpsc::channel();
+        let mut response_rxs = Vec::new();
+        for texts in [&["a", "b"][..], &["c"], &["d", "e"], &["f"]] {
+            let (request, response_rx) = request(texts);
+            request_tx.send(request).unwrap();
+            response_rxs.push(response_rx);
+        }
+
+        let first = collect_batch(&request_rx, 4, Duration::from_secs(1)).unwrap();
+        assert_eq!(first.len(), 3);
+        let second = collect_batch(&request_rx, 4, Duration::from_millis(10)).unwrap();
+        assert_eq!(second.len(), 1);
+
+        drop(request_tx);
+        assert!(collect_batch(&request_rx, 4, Duration::from_millis(10)).is_none());
+    }
+
+    #[test]
+    fn splits_batch_embeddings_by_request() {
+        let (first, first_rx) = request(&["a", "b"]);
+        let (second, second_rx) = request(&["c"]);
+
+        respond(
+            vec![first, second],
+            Ok(vec![vec![1.0], vec![2.0], vec![3.0]]),
+        );
+
+        assert_eq!(first_rx.recv().unwrap(), Ok(vec![vec![1.0], vec![2.0]]));
+        assert_eq!(second_rx.recv().unwrap(), Ok(vec![vec![3.0]]));
+    }
+
+    #[test]
+    fn sends_error_to_every_request_when_counts_differ() {
+        let (first, first_rx) = request(&["a", "b"]);
+        let (second, second_rx) = request(&["c"]);
+
+        respond(vec![first, second], Ok(vec![vec![1.0]]));
+
+        assert!(first_rx.recv().unwrap().is_err());
+        assert!(second_rx.recv().unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn embeds_with_hash_backend_without_model_download() {
+        let service = EmbeddingService::with_config(EmbedderConfig::hash(64), 1);
+        let embeddings = service
+            .embed_many(vec![
+                "국회 예산안 심사".to_string(),
+                "국회 예산안 심사".to_string(),
+                "프로야구 개막".to_string(),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(embeddings.len(), 3);
+        assert_eq!(embeddings[0], embeddings[1]);
+        assert_ne!(embeddings[0], embeddings[2]);
+        assert_eq!(service.dimension(), Some(64));
+        assert_eq!(service.model_id(), "hash-64");
+    }
+
+    #[test]
+    fn normalizes_to_unit_length() {
+        let mut embedding = vec![3.0, 4.0];
+        normalize(&mut embedding);
+        assert_eq!(embedding, vec![0.6, 0.8]);
+
+        let mut zero = vec![0.0, 0.0];
+        normalize(&mut zero);
+        assert_eq!(zero, vec![0.0, 0.0]);
+    }
+}
@@ -0,0 +1,7 @@
+use sha2::{Digest, Sha256};
+
+/// 공백을 정리한 텍스트의 SHA-256 해시(hex). 공백/줄바꿈 차이만 있는 같은 본문은 같은 해시를 가짐.
+pub fn normalized_content_hash(text: &str) -> String {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!("{:x}", Sha256::digest(normalized.as_bytes()))
+}
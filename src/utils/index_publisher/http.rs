@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use reqwest::Client;
+
+use crate::model::error::OmniNewsError;
+
+use super::{file_name, non_empty_env, IndexPublisher, PublishedIndex};
+
+/// 백엔드의 업로드 엔드포인트에 파일마다 `PUT {ANNOY_PUBLISH_HTTP_URL}/{파일 이름}`으로 올림.
+pub struct HttpPublisher {
+    base_url: String,
+    token: Option<String>,
+}
+
+impl HttpPublisher {
+    pub fn from_env() -> Result<Self, OmniNewsError> {
+        let base_url = non_empty_env("ANNOY_PUBLISH_HTTP_URL").ok_or_else(|| {
+            OmniNewsError::Publish("ANNOY_PUBLISH_HTTP_URL is not set".to_string())
+        })?;
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: non_empty_env("ANNOY_PUBLISH_HTTP_TOKEN"),
+        })
+    }
+}
+
+#[async_trait]
+impl IndexPublisher for HttpPublisher {
+    fn name(&self) -> &str {
+        "http"
+    }
+
+    async fn publish(
+        &self,
+        client: &Client,
+        index: &PublishedIndex,
+    ) -> Result<String, OmniNewsError> {
+        for path in index.files() {
+            let url = format!("{}/{}", self.base_url, file_name(path));
+            let mut request = client
+                .put(&url)
+                .header("X-Index-Kind", &index.kind)
+                .header("X-Index-Version", index.version)
+                .body(tokio::fs::read(path).await?);
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+
+            let resp = request.send().await?;
+            if !resp.status().is_success() {
+                return Err(OmniNewsError::Publish(format!(
+                    "PUT {} returned {}",
+                    url,
+                    resp.status()
+                )));
+            }
+        }
+
+        Ok(format!(
+            "{}/{}",
+            self.base_url,
+            file_name(&index.index_path)
+        ))
+    }
+}
@@ -0,0 +1,159 @@
+pub mod http;
+pub mod s3;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+
+use crate::{annoy_info, annoy_warn, model::error::OmniNewsError};
+
+use self::{http::HttpPublisher, s3::S3Publisher};
+
+/// 로컬 디렉토리에 게시된 인덱스 한 버전. 원격 게시와 웹훅 알림에 사용함.
+#[derive(Debug, Clone)]
+pub struct PublishedIndex {
+    pub kind: String,
+    pub version: u64,
+    pub model_id: String,
+    pub model_version: i32,
+    pub dimension: usize,
+    pub items: usize,
+    pub built_at: String,
+    // `{index}.v{version}`, `{index}`, `{index}.manifest.json` 순서로 올려야 함
+    pub versioned_path: PathBuf,
+    pub index_path: PathBuf,
+    pub manifest_path: PathBuf,
+}
+
+impl PublishedIndex {
+    /// 업로드 순서대로의 파일 목록. 매니페스트를 마지막에 올려, 매니페스트를 먼저 읽는 쪽이
+    /// 아직 올라가지 않은 버전을 가리키지 않도록 함.
+    pub fn files(&self) -> [&Path; 3] {
+        [&self.versioned_path, &self.index_path, &self.manifest_path]
+    }
+}
+
+#[async_trait]
+pub trait IndexPublisher: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// 인덱스 파일을 올리고 게시한 위치를 반환함.
+    async fn publish(
+        &self,
+        client: &Client,
+        index: &PublishedIndex,
+    ) -> Result<String, OmniNewsError>;
+}
+
+/// 로컬 빌드 디렉토리 외에 추가로 인덱스를 올릴 곳과 새 버전 알림 웹훅.
+pub struct IndexPublishers {
+    client: Client,
+    publishers: Vec<Box<dyn IndexPublisher>>,
+    webhook_url: Option<String>,
+    webhook_token: Option<String>,
+}
+
+impl IndexPublishers {
+    /// `ANNOY_PUBLISH_TARGETS`(예: `s3,http`)에 적힌 대상으로 구성함.
+    /// 설정이 부족한 대상은 경고 후 제외함.
+    pub fn from_env() -> Self {
+        let targets = env::var("ANNOY_PUBLISH_TARGETS").unwrap_or_default();
+
+        let mut publishers: Vec<Box<dyn IndexPublisher>> = Vec::new();
+        for name in targets.split(',').map(|n| n.trim().to_lowercase()) {
+            let publisher: Result<Box<dyn IndexPublisher>, OmniNewsError> = match name.as_str() {
+                "s3" => S3Publisher::from_env().map(|p| Box::new(p) as Box<dyn IndexPublisher>),
+                "http" => HttpPublisher::from_env().map(|p| Box::new(p) as Box<dyn IndexPublisher>),
+                // 로컬 디렉토리(`ANNOY_INDEX_DIR`)에는 항상 게시함
+                "" | "local" => continue,
+                _ => {
+                    annoy_warn!("[Publisher] Unknown publish target: {}", name);
+                    continue;
+                }
+            };
+            match publisher {
+                Ok(publisher) => publishers.push(publisher),
+                Err(e) => annoy_warn!("[Publisher] Skip publish target {}: {}", name, e),
+            }
+        }
+
+        Self {
+            client: Client::new(),
+            publishers,
+            webhook_url: non_empty_env("ANNOY_PUBLISH_WEBHOOK_URL"),
+            webhook_token: non_empty_env("ANNOY_PUBLISH_WEBHOOK_TOKEN"),
+        }
+    }
+
+    /// 설정된 대상에 모두 올린 뒤 웹훅으로 알림. 한 대상이 실패해도 나머지는 계속 진행하며,
+    /// 웹훅에는 성공한 위치만 담음.
+    pub async fn publish(&self, index: &PublishedIndex) {
+        let mut locations = vec![index.index_path.display().to_string()];
+        for publisher in &self.publishers {
+            match publisher.publish(&self.client, index).await {
+                Ok(location) => {
+                    annoy_info!(
+                        "[Publisher] Published {} index v{} to {}",
+                        index.kind,
+                        index.version,
+                        location
+                    );
+                    locations.push(location);
+                }
+                Err(e) => annoy_warn!(
+                    "[Publisher] Failed to publish {} index v{} with {}: {}",
+                    index.kind,
+                    index.version,
+                    publisher.name(),
+                    e
+                ),
+            }
+        }
+
+        self.notify(index, &locations).await;
+    }
+
+    async fn notify(&self, index: &PublishedIndex, locations: &[String]) {
+        let Some(webhook_url) = &self.webhook_url else {
+            return;
+        };
+
+        let mut request = self.client.post(webhook_url).json(&json!({
+            "event": "annoy_index_published",
+            "kind": index.kind,
+            "version": index.version,
+            "model_id": index.model_id,
+            "model_version": index.model_version,
+            "dimension": index.dimension,
+            "items": index.items,
+            "built_at": index.built_at,
+            "file": file_name(&index.versioned_path),
+            "manifest": file_name(&index.manifest_path),
+            "locations": locations,
+        }));
+        if let Some(token) = &self.webhook_token {
+            request = request.bearer_auth(token);
+        }
+
+        match request.send().await {
+            Ok(resp) if resp.status().is_success() => (),
+            Ok(resp) => annoy_warn!("[Publisher] Index webhook returned {}", resp.status()),
+            Err(e) => annoy_warn!("[Publisher] Failed to send index webhook: {}", e),
+        }
+    }
+}
+
+fn non_empty_env(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.trim().is_empty())
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
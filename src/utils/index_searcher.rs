@@ -0,0 +1,165 @@
+use std::{
+    collections::HashMap,
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use tokio::sync::oneshot;
+
+use crate::{
+    annoy_error, annoy_info, annoy_warn,
+    model::{error::OmniNewsError, rss::NewticleType},
+    utils::{
+        annoy_util::{current_index, CurrentIndex},
+        vector_index::VectorIndex,
+    },
+};
+
+type SearchResponse = Result<Vec<(i32, f32)>, OmniNewsError>;
+
+struct SearchRequest {
+    category: NewticleType,
+    model_version: i32,
+    query: Vec<f32>,
+    k: usize,
+    response_tx: oneshot::Sender<SearchResponse>,
+}
+
+struct LoadedIndex {
+    path: PathBuf,
+    index: Box<dyn VectorIndex>,
+}
+
+/// 스케줄러가 게시한 인덱스로 최근접 이웃을 찾음. Annoy 인덱스는 스레드 사이로 옮길 수 없으므로
+/// 읽은 인덱스는 검색 스레드가 들고 있고, 요청마다 매니페스트를 확인해 새 버전이 게시되었으면 다시 읽음.
+#[derive(Clone)]
+pub struct IndexSearcher {
+    request_tx: Arc<Mutex<mpsc::Sender<SearchRequest>>>,
+}
+
+impl IndexSearcher {
+    pub fn new() -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<SearchRequest>();
+
+        thread::spawn(move || {
+            let mut loaded = HashMap::new();
+            while let Ok(request) = request_rx.recv() {
+                // 손상된 인덱스 등으로 검색이 패닉해도 스레드는 계속 요청을 받도록 함
+                let result =
+                    panic::catch_unwind(AssertUnwindSafe(|| search(&mut loaded, &request)))
+                        .unwrap_or_else(|_| {
+                            annoy_error!("[Searcher] Search panicked, drop loaded indexes");
+                            // 패닉 도중의 인덱스 상태는 믿을 수 없으므로 다음 요청에서 다시 읽음
+                            loaded.clear();
+                            Err(searcher_error("Search panicked"))
+                        });
+                let _ = request.response_tx.send(result);
+            }
+            annoy_info!("[Searcher] Index searcher thread terminated");
+        });
+
+        Self {
+            request_tx: Arc::new(Mutex::new(request_tx)),
+        }
+    }
+
+    /// `query`와 가까운 `(임베딩 ID, 코사인 유사도)`를 최대 `k`개 반환함.
+    /// 게시된 인덱스가 없거나 `model_version`으로 만든 인덱스가 아니면 `NotFound`.
+    pub async fn search(
+        &self,
+        category: &NewticleType,
+        model_version: i32,
+        query: Vec<f32>,
+        k: usize,
+    ) -> Result<Vec<(i32, f32)>, OmniNewsError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        let request = SearchRequest {
+            category: category.clone(),
+            model_version,
+            query,
+            k,
+            response_tx,
+        };
+
+        self.request_tx
+            .lock()
+            .map_err(|_| searcher_error("Failed to acquire lock"))?
+            .send(request)
+            .map_err(|_| searcher_error("Failed to request to searcher thread"))?;
+
+        response_rx
+            .await
+            .map_err(|_| searcher_error("Failed to receive from searcher thread"))?
+    }
+}
+
+fn search(
+    loaded: &mut HashMap<&'static str, LoadedIndex>,
+    request: &SearchRequest,
+) -> SearchResponse {
+    let Some(current) = current_index(&request.category) else {
+        return Err(OmniNewsError::NotFound(format!(
+            "{:?} index",
+            request.category
+        )));
+    };
+    // 모델이 바뀐 직후에는 인덱스가 아직 이전 모델로 만들어져 있을 수 있음
+    if current.model_version != request.model_version {
+        return Err(OmniNewsError::NotFound(format!(
+            "{} index for embedding model version {} (current: {})",
+            current.kind, request.model_version, current.model_version
+        )));
+    }
+
+    if loaded
+        .get(current.kind)
+        .is_none_or(|index| index.path != current.path)
+    {
+        loaded.insert(current.kind, load(&current)?);
+    }
+    let index = &loaded[current.kind].index;
+
+    index
+        .search(&request.query, request.k)
+        .map(|hits| {
+            hits.into_iter()
+                .map(|(embedding_id, distance)| {
+                    (embedding_id, current.index_type.similarity(distance))
+                })
+                .collect()
+        })
+        .map_err(searcher_error)
+}
+
+fn load(current: &CurrentIndex) -> Result<LoadedIndex, OmniNewsError> {
+    let index = current
+        .index_type
+        .load(&current.path, current.dimension)
+        .map_err(|e| {
+            annoy_warn!(
+                "[Searcher] Failed to load {} index {}: {}",
+                current.kind,
+                current.path.display(),
+                e
+            );
+            searcher_error(e)
+        })?;
+    annoy_info!(
+        "[Searcher] Loaded {} index version {} ({} items)",
+        current.kind,
+        current.version,
+        index.len()
+    );
+
+    Ok(LoadedIndex {
+        path: current.path.clone(),
+        index,
+    })
+}
+
+fn searcher_error(e: impl ToString) -> OmniNewsError {
+    OmniNewsError::Io(io::Error::other(e.to_string()))
+}
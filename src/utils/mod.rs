@@ -1,4 +1,10 @@
 pub mod annoy_util;
-pub mod api;
+pub mod date_util;
+pub mod embedder;
 pub mod embedding_util;
 pub mod firebase;
+pub mod hash_util;
+pub mod index_publisher;
+pub mod index_searcher;
+pub mod summarizer;
+pub mod vector_index;
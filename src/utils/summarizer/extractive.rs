@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+
+use crate::model::{analysis::ArticleAnalysis, error::LlmError};
+
+use super::{ModelTier, Summarizer, Summary, SummaryRequest, TokenUsage};
+
+/// API를 호출하지 않는 마지막 fallback. 본문 앞 문장들을 목표 길이만큼 잘라 사용함.
+pub struct ExtractiveSummarizer;
+
+#[async_trait]
+impl Summarizer for ExtractiveSummarizer {
+    fn name(&self) -> &str {
+        "extractive"
+    }
+
+    // 추출 요약은 비용이 없으므로 캐시하지 않음
+    fn models(&self, _tier: ModelTier) -> Vec<String> {
+        Vec::new()
+    }
+
+    // 요약만 채우고 키워드, 카테고리 등은 비워 둠
+    fn structured(&self) -> bool {
+        false
+    }
+
+    async fn summarize(&self, request: &SummaryRequest) -> Result<Summary, LlmError> {
+        let max_len = request.target_length + 10;
+        let mut text = String::new();
+
+        for sentence in split_sentences(&request.content) {
+            if !text.is_empty() && text.chars().count() + sentence.chars().count() > max_len {
+                break;
+            }
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(sentence);
+        }
+
+        // 첫 문장부터 너무 길면 글자 수로 자름
+        if text.chars().count() > max_len {
+            text = text.chars().take(max_len).collect();
+        }
+
+        if text.is_empty() {
+            return Err(LlmError::EmptyResponse);
+        }
+
+        Ok(Summary {
+            analysis: ArticleAnalysis {
+                summary: text,
+                ..Default::default()
+            },
+            provider: self.name().to_string(),
+            model: "extractive".to_string(),
+            usage: TokenUsage::default(),
+            discarded: Vec::new(),
+        })
+    }
+}
+
+fn split_sentences(content: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    for (i, c) in content.char_indices() {
+        if matches!(c, '.' | '?' | '!' | '\n') {
+            let end = i + c.len_utf8();
+            let sentence = content[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+    let rest = content[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
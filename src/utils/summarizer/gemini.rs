@@ -0,0 +1,239 @@
+use std::env;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::model::{analysis::ANALYSIS_CATEGORIES, error::LlmError};
+
+use super::{
+    build_prompt, economy_model_from_env, parse_analysis, DiscardedUsage, ModelTier, Summarizer,
+    Summary, SummaryRequest, TokenUsage,
+};
+
+const DEFAULT_GEMINI_MODEL: &str = "gemini-2.0-flash-lite-001";
+const DEFAULT_GEMINI_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+
+/*
+*╰─ curl "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent" \
+*  -H 'Content-Type: application/json' \
+*  -H 'X-goog-api-key: $GEMINI_API_KEY' \
+*  -X POST \
+*  -d '{
+*    "contents": [
+*      {
+*        "parts": [
+*          {
+*            "text": "ㅎㅇ"
+*          }
+*        ]
+*      }
+*    ]
+*  }'
+*
+* */
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChatRequest {
+    contents: Vec<Content>,
+    generation_config: GenerationConfig,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerationConfig {
+    response_mime_type: String,
+    response_schema: serde_json::Value,
+}
+
+/*
+*{
+  "candidates": [
+    {
+      "content": {
+        "parts": [
+          {
+            "text": "안녕하세요! 무엇을 도와드릴까요? 😊\n"
+          }
+        ],
+        "role": "model"
+      },
+      "finishReason": "STOP",
+      "avgLogprobs": -0.19659008085727692
+    }
+  ],
+...
+}
+*/
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChatResponse {
+    #[serde(default)]
+    candidates: Vec<Candidate>,
+    usage_metadata: Option<UsageMetadata>,
+}
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsageMetadata {
+    #[serde(default)]
+    prompt_token_count: i64,
+    #[serde(default)]
+    candidates_token_count: i64,
+}
+#[derive(Debug, Serialize, Deserialize)]
+struct Candidate {
+    content: Content,
+}
+#[derive(Debug, Serialize, Deserialize)]
+struct Content {
+    #[serde(default)]
+    parts: Vec<Parts>,
+}
+#[derive(Debug, Serialize, Deserialize)]
+struct Parts {
+    text: String,
+}
+
+pub struct GeminiSummarizer {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    model: String,
+    economy_model: Option<String>,
+}
+
+impl GeminiSummarizer {
+    pub fn from_env() -> Result<Self, LlmError> {
+        let api_key = env::var("GEMINI_API_KEY")
+            .map_err(|_| LlmError::MissingApiKey("GEMINI_API_KEY".to_string()))?;
+
+        let model = env::var("GEMINI_MODEL").unwrap_or_else(|_| DEFAULT_GEMINI_MODEL.to_string());
+        Ok(Self {
+            client: Client::new(),
+            api_key,
+            base_url: env::var("GEMINI_API_BASE_URL")
+                .unwrap_or_else(|_| DEFAULT_GEMINI_BASE_URL.to_string()),
+            economy_model: economy_model_from_env("GEMINI_ECONOMY_MODEL", &model),
+            model,
+        })
+    }
+
+    fn model_for(&self, tier: ModelTier) -> &str {
+        match tier {
+            ModelTier::Default => &self.model,
+            ModelTier::Economy => self.economy_model.as_deref().unwrap_or(&self.model),
+        }
+    }
+}
+
+#[async_trait]
+impl Summarizer for GeminiSummarizer {
+    fn name(&self) -> &str {
+        "gemini"
+    }
+
+    fn models(&self, tier: ModelTier) -> Vec<String> {
+        vec![self.model_for(tier).to_string()]
+    }
+
+    async fn summarize(&self, request: &SummaryRequest) -> Result<Summary, LlmError> {
+        let request_body = ChatRequest {
+            contents: vec![Content {
+                parts: vec![Parts {
+                    text: build_prompt(request),
+                }],
+            }],
+            generation_config: GenerationConfig {
+                response_mime_type: "application/json".to_string(),
+                response_schema: response_schema(),
+            },
+        };
+
+        let model = self.model_for(request.model_tier);
+        let resp = self
+            .client
+            .post(format!(
+                "{}/models/{}:generateContent",
+                self.base_url, model
+            ))
+            .header("Content-Type", "application/json")
+            .header("x-goog-api-key", &self.api_key)
+            .json(&request_body)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(LlmError::Status(status.as_u16(), body));
+        }
+
+        let parsed = resp.json::<ChatResponse>().await?;
+        // 후보가 없거나 비어 있는 응답도 패닉 없이 에러로 처리함
+        let text = parsed
+            .candidates
+            .first()
+            .and_then(|candidate| candidate.content.parts.first())
+            .map(|part| part.text.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .ok_or(LlmError::EmptyResponse)?;
+
+        let usage = parsed
+            .usage_metadata
+            .map(|usage| TokenUsage {
+                input_tokens: usage.prompt_token_count,
+                output_tokens: usage.candidates_token_count,
+            })
+            .unwrap_or_default();
+
+        let analysis = parse_analysis(&text).map_err(|e| {
+            LlmError::UnparsableOutput(
+                e.to_string(),
+                DiscardedUsage {
+                    provider: self.name().to_string(),
+                    model: model.to_string(),
+                    usage,
+                },
+            )
+        })?;
+
+        Ok(Summary {
+            analysis,
+            provider: self.name().to_string(),
+            model: model.to_string(),
+            usage,
+            discarded: Vec::new(),
+        })
+    }
+}
+
+// Gemini `responseSchema`는 OpenAPI 스키마의 일부만 지원하므로 별도로 정의함.
+fn response_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "OBJECT",
+        "properties": {
+            "summary": { "type": "STRING" },
+            "keywords": {
+                "type": "ARRAY",
+                "items": { "type": "STRING" },
+                "minItems": 3,
+                "maxItems": 5
+            },
+            "category": { "type": "STRING", "enum": ANALYSIS_CATEGORIES },
+            "entities": {
+                "type": "ARRAY",
+                "items": {
+                    "type": "OBJECT",
+                    "properties": {
+                        "name": { "type": "STRING" },
+                        "type": { "type": "STRING" }
+                    },
+                    "required": ["name", "type"]
+                }
+            },
+            "sentiment": { "type": "STRING", "enum": ["positive", "neutral", "negative"] }
+        },
+        "required": ["summary", "keywords", "category", "entities", "sentiment"],
+        "propertyOrdering": ["summary", "keywords", "category", "entities", "sentiment"]
+    })
+}
@@ -0,0 +1,347 @@
+pub mod extractive;
+pub mod gemini;
+pub mod openai;
+pub mod pricing;
+
+use std::env;
+
+use async_trait::async_trait;
+
+use crate::{
+    config::env::get_env_or,
+    model::{
+        analysis::{normalize_category, ArticleAnalysis, ANALYSIS_CATEGORIES},
+        error::LlmError,
+    },
+    news_info, news_warn,
+};
+
+pub use crate::model::llm_usage::{DiscardedUsage, TokenUsage};
+
+use self::{extractive::ExtractiveSummarizer, gemini::GeminiSummarizer, openai::OpenAiSummarizer};
+
+/// 예산 상황에 따라 사용할 모델 등급.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelTier {
+    Default,
+    // 예산이 얼마 남지 않았을 때 사용하는 저렴한 모델
+    Economy,
+}
+
+/// 절약 단계 모델을 환경변수에서 읽음. 설정하지 않았거나 기본 모델과 같으면
+/// 절약 효과가 없으므로 절약 단계에서도 기본 모델을 사용함.
+/// 설정하지 않은 것은 기본 구성이므로 info로, 기본 모델과 같은 것은 설정 실수이므로 warn으로 남김.
+pub fn economy_model_from_env(key: &str, model: &str) -> Option<String> {
+    let economy_model = env::var(key).ok().filter(|value| !value.is_empty());
+    match economy_model {
+        Some(value) if value != model => Some(value),
+        Some(_) => {
+            news_warn!(
+                "[Summarizer] {} is same as {}, economy tier is skipped",
+                key,
+                model
+            );
+            None
+        }
+        None => {
+            news_info!("[Summarizer] {} is not set, economy tier is skipped", key);
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SummaryRequest {
+    pub content: String,
+    // 목표 요약 글자 수. 프롬프트에서는 ±10자 범위로 요청함.
+    pub target_length: usize,
+    pub model_tier: ModelTier,
+}
+
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub analysis: ArticleAnalysis,
+    pub provider: String,
+    pub model: String,
+    pub usage: TokenUsage,
+    pub discarded: Vec<DiscardedUsage>,
+}
+
+// 프롬프트를 바꾸면 올려야 함. 요약 캐시는 이 버전이 다른 항목을 사용하지 않음.
+// 2: 요약, 키워드, 카테고리, 개체명, 감성을 JSON으로 한 번에 받음
+pub const SUMMARY_PROMPT_VERSION: i32 = 2;
+
+const MIN_KEYWORDS: usize = 3;
+const MAX_KEYWORDS: usize = 5;
+
+#[async_trait]
+pub trait Summarizer: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// 해당 등급에서 사용할 모델 목록(우선순위 순). 캐시 대상이 아니면 비어 있음.
+    fn models(&self, tier: ModelTier) -> Vec<String>;
+
+    /// JSON 스키마로 응답을 강제하는 provider인지 여부. 아니면 요약만 채워서 반환하며 검증하지 않음.
+    fn structured(&self) -> bool {
+        true
+    }
+
+    async fn summarize(&self, request: &SummaryRequest) -> Result<Summary, LlmError>;
+}
+
+/// 설정된 순서대로 요약을 시도하고, 실패하면 다음 provider로 넘어감.
+pub struct SummarizerChain {
+    providers: Vec<Box<dyn Summarizer>>,
+}
+
+impl SummarizerChain {
+    pub fn new(providers: Vec<Box<dyn Summarizer>>) -> Self {
+        Self { providers }
+    }
+
+    /// `SUMMARIZER_PROVIDERS`(예: `gemini,openai,extractive`)에 적힌 순서로 체인을 구성함.
+    /// 설정이 부족한 provider는 경고 후 제외함.
+    pub fn from_env() -> Self {
+        let names =
+            env::var("SUMMARIZER_PROVIDERS").unwrap_or_else(|_| "gemini,extractive".to_string());
+
+        let mut providers: Vec<Box<dyn Summarizer>> = Vec::new();
+        for name in names.split(',').map(|n| n.trim().to_lowercase()) {
+            let provider: Result<Box<dyn Summarizer>, LlmError> = match name.as_str() {
+                "gemini" => {
+                    GeminiSummarizer::from_env().map(|p| Box::new(p) as Box<dyn Summarizer>)
+                }
+                "openai" => {
+                    OpenAiSummarizer::from_env().map(|p| Box::new(p) as Box<dyn Summarizer>)
+                }
+                "extractive" => Ok(Box::new(ExtractiveSummarizer) as Box<dyn Summarizer>),
+                "" => continue,
+                _ => {
+                    news_warn!("[Summarizer] Unknown provider: {}", name);
+                    continue;
+                }
+            };
+            match provider {
+                Ok(provider) => providers.push(provider),
+                Err(e) => news_warn!("[Summarizer] Skip provider {}: {}", name, e),
+            }
+        }
+
+        news_info!(
+            "[Summarizer] Providers: {}",
+            providers
+                .iter()
+                .map(|p| p.name())
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        );
+        Self::new(providers)
+    }
+}
+
+#[async_trait]
+impl Summarizer for SummarizerChain {
+    fn name(&self) -> &str {
+        "chain"
+    }
+
+    fn models(&self, tier: ModelTier) -> Vec<String> {
+        self.providers.iter().flat_map(|p| p.models(tier)).collect()
+    }
+
+    /// 구조화된 응답은 검증하고, 규칙을 어기면 `LLM_MAX_VALIDATION_RETRIES`번까지 다시 요청함.
+    /// 그래도 실패하면 다음 provider로 넘어감.
+    async fn summarize(&self, request: &SummaryRequest) -> Result<Summary, LlmError> {
+        let max_retries = get_env_or("LLM_MAX_VALIDATION_RETRIES", 2_usize);
+        let mut discarded = Vec::new();
+
+        for provider in &self.providers {
+            let attempts = if provider.structured() {
+                max_retries + 1
+            } else {
+                1
+            };
+
+            for attempt in 1..=attempts {
+                let summary = match provider.summarize(request).await {
+                    Ok(summary) => summary,
+                    // 파싱하지 못한 응답(스키마를 따르지 않은 JSON 포함)도 토큰은 사용했으므로
+                    // 버려진 사용량으로 남기고 재시도함
+                    Err(LlmError::UnparsableOutput(e, usage)) => {
+                        discard_invalid_output(
+                            provider.name(),
+                            attempt,
+                            attempts,
+                            &e,
+                            usage,
+                            &mut discarded,
+                        );
+                        continue;
+                    }
+                    Err(e) => {
+                        news_warn!("[Summarizer] {} failed, fallback: {}", provider.name(), e);
+                        break;
+                    }
+                };
+
+                if !provider.structured() {
+                    return Ok(Summary {
+                        discarded,
+                        ..summary
+                    });
+                }
+
+                match validate_analysis(&summary.analysis, request.target_length) {
+                    Ok(analysis) => {
+                        return Ok(Summary {
+                            analysis,
+                            discarded,
+                            ..summary
+                        })
+                    }
+                    Err(e) => discard_invalid_output(
+                        provider.name(),
+                        attempt,
+                        attempts,
+                        &e,
+                        DiscardedUsage {
+                            provider: summary.provider,
+                            model: summary.model,
+                            usage: summary.usage,
+                        },
+                        &mut discarded,
+                    ),
+                }
+            }
+        }
+        Err(LlmError::AllProvidersFailed(discarded))
+    }
+}
+
+/// 검증에 실패한 응답을 로그로 남기고, 사용한 토큰을 버려진 사용량에 더함.
+fn discard_invalid_output(
+    provider: &str,
+    attempt: usize,
+    attempts: usize,
+    e: &dyn std::fmt::Display,
+    usage: DiscardedUsage,
+    discarded: &mut Vec<DiscardedUsage>,
+) {
+    news_warn!(
+        "[Summarizer] {} returned invalid output ({}/{}): {}",
+        provider,
+        attempt,
+        attempts,
+        e
+    );
+    discarded.push(usage);
+}
+
+/// 요약 길이, 키워드 개수, 카테고리를 검사하고 정규화된 분석 결과를 반환함.
+pub fn validate_analysis(
+    analysis: &ArticleAnalysis,
+    target_length: usize,
+) -> Result<ArticleAnalysis, LlmError> {
+    let summary = analysis.summary.trim().to_string();
+    let summary_len = summary.chars().count();
+    let (min_len, max_len) = (target_length.saturating_sub(10), target_length + 10);
+    if summary_len < min_len || summary_len > max_len {
+        return Err(LlmError::InvalidOutput(format!(
+            "summary length {} not in {}..={}",
+            summary_len, min_len, max_len
+        )));
+    }
+
+    let mut keywords: Vec<String> = Vec::new();
+    for keyword in analysis.keywords.iter().map(|k| k.trim()) {
+        if !keyword.is_empty() && !keywords.iter().any(|k| k == keyword) {
+            keywords.push(keyword.to_string());
+        }
+    }
+    if keywords.len() < MIN_KEYWORDS || keywords.len() > MAX_KEYWORDS {
+        return Err(LlmError::InvalidOutput(format!(
+            "{} keywords, expected {}..={}",
+            keywords.len(),
+            MIN_KEYWORDS,
+            MAX_KEYWORDS
+        )));
+    }
+
+    let category = normalize_category(&analysis.category).ok_or_else(|| {
+        LlmError::InvalidOutput(format!("unknown category: {}", analysis.category))
+    })?;
+
+    let entities = analysis
+        .entities
+        .iter()
+        .filter(|entity| !entity.name.trim().is_empty())
+        .cloned()
+        .collect();
+
+    Ok(ArticleAnalysis {
+        summary,
+        keywords,
+        category: category.to_string(),
+        entities,
+        sentiment: analysis.sentiment,
+    })
+}
+
+/// 응답 본문의 JSON을 분석 결과로 변환함. 코드 블록으로 감싼 응답도 허용함.
+pub fn parse_analysis(text: &str) -> Result<ArticleAnalysis, LlmError> {
+    let json = text
+        .trim()
+        .trim_start_matches("```json")
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim();
+
+    serde_json::from_str(json).map_err(|e| LlmError::InvalidOutput(e.to_string()))
+}
+
+pub fn build_prompt(request: &SummaryRequest) -> String {
+    format!(
+        "아래 뉴스 기사를 분석해 JSON으로 답해 주세요.\n\
+    - summary: 객관적 서술체(‘~한다’, ‘~로 보인다’, ‘~라고 밝혔다’)로 {}자 이상 {}자 이하 요약\n\
+    - keywords: 핵심 키워드 {}~{}개\n\
+    - category: 다음 중 하나 ({})\n\
+    - entities: 기사에 등장하는 인물(person), 기관(organization), 장소(location) 등의 이름과 종류\n\
+    - sentiment: positive, neutral, negative 중 하나\n\n{}",
+        request.target_length.saturating_sub(10),
+        request.target_length + 10,
+        MIN_KEYWORDS,
+        MAX_KEYWORDS,
+        ANALYSIS_CATEGORIES.join(", "),
+        request.content
+    )
+}
+
+/// OpenAI `response_format`에 사용하는 JSON 스키마. Gemini는 자체 스키마 형식을 사용함.
+pub fn analysis_json_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "summary": { "type": "string" },
+            "keywords": {
+                "type": "array",
+                "items": { "type": "string" }
+            },
+            "category": { "type": "string", "enum": ANALYSIS_CATEGORIES },
+            "entities": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "type": { "type": "string" }
+                    },
+                    "required": ["name", "type"],
+                    "additionalProperties": false
+                }
+            },
+            "sentiment": { "type": "string", "enum": ["positive", "neutral", "negative"] }
+        },
+        "required": ["summary", "keywords", "category", "entities", "sentiment"],
+        "additionalProperties": false
+    })
+}
@@ -0,0 +1,177 @@
+use std::env;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::model::error::LlmError;
+
+use super::{
+    analysis_json_schema, build_prompt, economy_model_from_env, parse_analysis, DiscardedUsage,
+    ModelTier, Summarizer, Summary, SummaryRequest, TokenUsage,
+};
+
+/// OpenAI Chat Completions 호환 API. OpenAI뿐 아니라 llama.cpp server, Ollama(`/v1`) 등
+/// 로컬 서버도 `OPENAI_COMPAT_BASE_URL`만 바꿔서 사용할 수 있음.
+pub struct OpenAiSummarizer {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+    model: String,
+    economy_model: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatCompletionRequest {
+    model: String,
+    messages: Vec<Message>,
+    temperature: f32,
+    response_format: ResponseFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct ResponseFormat {
+    #[serde(rename = "type")]
+    format_type: String,
+    json_schema: JsonSchema,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSchema {
+    name: String,
+    strict: bool,
+    schema: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Message {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionResponse {
+    #[serde(default)]
+    choices: Vec<Choice>,
+    usage: Option<Usage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Usage {
+    #[serde(default)]
+    prompt_tokens: i64,
+    #[serde(default)]
+    completion_tokens: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Choice {
+    message: Message,
+}
+
+impl OpenAiSummarizer {
+    pub fn from_env() -> Result<Self, LlmError> {
+        let base_url = env::var("OPENAI_COMPAT_BASE_URL")
+            .map_err(|_| LlmError::MissingConfig("OPENAI_COMPAT_BASE_URL".to_string()))?;
+
+        let model = env::var("OPENAI_COMPAT_MODEL").unwrap_or_else(|_| "gpt-4o-mini".to_string());
+        Ok(Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            // 로컬 서버는 키가 필요 없음
+            api_key: env::var("OPENAI_COMPAT_API_KEY")
+                .ok()
+                .filter(|k| !k.is_empty()),
+            economy_model: economy_model_from_env("OPENAI_COMPAT_ECONOMY_MODEL", &model),
+            model,
+        })
+    }
+
+    fn model_for(&self, tier: ModelTier) -> &str {
+        match tier {
+            ModelTier::Default => &self.model,
+            ModelTier::Economy => self.economy_model.as_deref().unwrap_or(&self.model),
+        }
+    }
+}
+
+#[async_trait]
+impl Summarizer for OpenAiSummarizer {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    fn models(&self, tier: ModelTier) -> Vec<String> {
+        vec![self.model_for(tier).to_string()]
+    }
+
+    async fn summarize(&self, request: &SummaryRequest) -> Result<Summary, LlmError> {
+        let model = self.model_for(request.model_tier);
+        let request_body = ChatCompletionRequest {
+            model: model.to_string(),
+            messages: vec![Message {
+                role: "user".to_string(),
+                content: build_prompt(request),
+            }],
+            temperature: 0.2,
+            response_format: ResponseFormat {
+                format_type: "json_schema".to_string(),
+                json_schema: JsonSchema {
+                    name: "article_analysis".to_string(),
+                    strict: true,
+                    schema: analysis_json_schema(),
+                },
+            },
+        };
+
+        let mut builder = self
+            .client
+            .post(format!("{}/chat/completions", self.base_url))
+            .json(&request_body);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+
+        let resp = builder.send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(LlmError::Status(status.as_u16(), body));
+        }
+
+        let parsed = resp.json::<ChatCompletionResponse>().await?;
+        let text = parsed
+            .choices
+            .first()
+            .map(|choice| choice.message.content.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .ok_or(LlmError::EmptyResponse)?;
+
+        let usage = parsed
+            .usage
+            .map(|usage| TokenUsage {
+                input_tokens: usage.prompt_tokens,
+                output_tokens: usage.completion_tokens,
+            })
+            .unwrap_or_default();
+
+        let analysis = parse_analysis(&text).map_err(|e| {
+            LlmError::UnparsableOutput(
+                e.to_string(),
+                DiscardedUsage {
+                    provider: self.name().to_string(),
+                    model: model.to_string(),
+                    usage,
+                },
+            )
+        })?;
+
+        Ok(Summary {
+            analysis,
+            provider: self.name().to_string(),
+            model: model.to_string(),
+            usage,
+            discarded: Vec::new(),
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use std::env;
+
+use crate::config::env::get_env_or;
+
+use super::TokenUsage;
+
+// 모델별 100만 토큰당 가격(USD, 입력/출력). `LLM_MODEL_PRICES`로 덮어쓸 수 있음.
+const DEFAULT_MODEL_PRICES: &[(&str, f64, f64)] = &[
+    ("gemini-2.0-flash-lite-001", 0.075, 0.30),
+    ("gemini-2.0-flash-lite", 0.075, 0.30),
+    ("gemini-2.0-flash", 0.10, 0.40),
+    ("gpt-4o-mini", 0.15, 0.60),
+];
+
+/// 토큰 사용량으로 예상 비용(원)을 계산함. 가격을 모르는 모델(로컬 모델 등)은 0원.
+pub fn estimate_cost_krw(model: &str, usage: &TokenUsage) -> f64 {
+    let (input_price, output_price) = model_price(model).unwrap_or((0.0, 0.0));
+    let usd = (usage.input_tokens as f64 * input_price + usage.output_tokens as f64 * output_price)
+        / 1_000_000.0;
+
+    usd * get_env_or("LLM_USD_TO_KRW", 1400.0)
+}
+
+/// `LLM_MODEL_PRICES=model:input:output,model:input:output` 형식을 우선 사용함.
+fn model_price(model: &str) -> Option<(f64, f64)> {
+    let configured = env::var("LLM_MODEL_PRICES").unwrap_or_default();
+    let from_env = configured.split(',').find_map(|entry| {
+        let mut parts = entry.trim().split(':');
+        let name = parts.next()?;
+        if name != model {
+            return None;
+        }
+        let input = parts.next()?.parse::<f64>().ok()?;
+        let output = parts.next()?.parse::<f64>().ok()?;
+        Some((input, output))
+    });
+
+    from_env.or_else(|| {
+        DEFAULT_MODEL_PRICES
+            .iter()
+            .find(|(name, _, _)| *name == model)
+            .map(|(_, input, output)| (*input, *output))
+    })
+}
@@ -0,0 +1,101 @@
+use std::{collections::HashSet, path::Path};
+
+use rannoy::Rannoy;
+
+use super::VectorIndex;
+
+// 트리 개수 증가: 40 -> 100 (`eval-index`로 비교해서 조정)
+const DEFAULT_N_TREES: i32 = 100;
+
+/// rannoy(C++ Annoy) 인덱스. 항목은 빌드 전에만 추가할 수 있고, `save`할 때 트리를 만듦.
+/// 한 번 만든 인덱스는 바꿀 수 없어 삭제는 지원하지 않음.
+pub struct AnnoyIndex {
+    annoy: Rannoy,
+    dimension: usize,
+    n_trees: i32,
+    built: bool,
+}
+
+impl AnnoyIndex {
+    pub fn new(dimension: usize) -> Self {
+        Self::with_trees(dimension, DEFAULT_N_TREES)
+    }
+
+    pub fn with_trees(dimension: usize, n_trees: i32) -> Self {
+        let annoy = Rannoy::new(dimension as i32);
+        annoy.set_seed(123);
+
+        Self {
+            annoy,
+            dimension,
+            n_trees,
+            built: false,
+        }
+    }
+
+    pub fn load(path: &Path, dimension: usize) -> Result<Self, String> {
+        if !path.exists() {
+            return Err(format!("{} does not exist", path.display()));
+        }
+        let annoy = Rannoy::new(dimension as i32);
+        annoy.load(path.to_path_buf());
+
+        Ok(Self {
+            annoy,
+            dimension,
+            n_trees: DEFAULT_N_TREES,
+            built: true,
+        })
+    }
+}
+
+impl VectorIndex for AnnoyIndex {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Annoy는 항목 ID를 배열 위치로 쓰므로 실제 항목 수가 아니라 가장 큰 ID + 1을 반환함.
+    fn len(&self) -> usize {
+        self.annoy.get_n_items().max(0) as usize
+    }
+
+    fn add(&mut self, id: i32, vector: &[f32]) -> Result<(), String> {
+        if self.built {
+            return Err("Annoy index is already built".to_string());
+        }
+        if vector.len() != self.dimension {
+            return Err(format!(
+                "Expected dimension {}, got {}",
+                self.dimension,
+                vector.len()
+            ));
+        }
+        self.annoy.add_item(id, vector);
+        Ok(())
+    }
+
+    fn remove(&mut self, _id: i32) -> Result<bool, String> {
+        Err("Annoy index does not support removal".to_string())
+    }
+
+    fn retain(&mut self, _ids: &HashSet<i32>) -> Result<usize, String> {
+        Err("Annoy index does not support removal".to_string())
+    }
+
+    fn search(&self, query: &[f32], k: usize) -> Result<Vec<(i32, f32)>, String> {
+        if !self.built {
+            return Err("Annoy index is not built".to_string());
+        }
+        let (ids, distances) = self.annoy.get_nns_by_vector(query.to_vec(), k as i32, -1);
+        Ok(ids.into_iter().zip(distances).collect())
+    }
+
+    fn save(&mut self, path: &Path) -> Result<(), String> {
+        if !self.built {
+            self.annoy.build(self.n_trees);
+            self.built = true;
+        }
+        self.annoy.save(path.to_path_buf());
+        Ok(())
+    }
+}
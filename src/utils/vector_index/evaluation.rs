@@ -0,0 +1,236 @@
+use std::{
+    collections::HashSet,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use super::{
+    annoy::AnnoyIndex,
+    hnsw::{dot, normalized, HnswIndex, HnswParams},
+    VectorIndex,
+};
+use crate::model::index_evaluation::IndexEvaluation;
+
+/// 비교할 인덱스 설정.
+#[derive(Debug, Clone)]
+pub enum IndexCandidate {
+    Annoy {
+        n_trees: i32,
+    },
+    // 같은 그래프를 한 번 만들고 `ef_search` 값마다 검색만 다시 함
+    Hnsw {
+        params: HnswParams,
+        ef_search: Vec<usize>,
+    },
+}
+
+/// 코사인 유사도 기준 정확한 top-k의 ID. `corpus`의 벡터는 정규화되어 있어야 함.
+pub fn brute_force_top_k(corpus: &[(i32, Vec<f32>)], query: &[f32], k: usize) -> Vec<i32> {
+    let query = normalized(query);
+    let mut scored = corpus
+        .iter()
+        .map(|(id, vector)| (*id, dot(&query, vector)))
+        .collect::<Vec<_>>();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored.into_iter().take(k).map(|(id, _)| id).collect()
+}
+
+/// 찾은 ID 중 정확한 top-k에 들어 있는 비율.
+pub fn recall_at_k(exact: &[i32], found: &[i32]) -> f64 {
+    if exact.is_empty() {
+        return 1.0;
+    }
+    let exact = exact.iter().collect::<HashSet<_>>();
+    let hits = found.iter().filter(|id| exact.contains(id)).count();
+    hits as f64 / exact.len() as f64
+}
+
+/// 후보 인덱스마다 `corpus`로 인덱스를 만들어 저장하고, `queries`로 recall@k와 검색 지연을 잼.
+/// `exact`는 쿼리별 정확한 top-k. 인덱스 파일은 `work_dir`에 만들었다가 지움.
+pub fn evaluate(
+    candidate: &IndexCandidate,
+    corpus: &[(i32, Vec<f32>)],
+    queries: &[Vec<f32>],
+    exact: &[Vec<i32>],
+    k: usize,
+    work_dir: &Path,
+) -> Result<Vec<IndexEvaluation>, String> {
+    let dimension = corpus.first().map(|(_, v)| v.len()).unwrap_or_default();
+    let path = work_dir.join(format!(
+        "omninews-eval-{}-{}.idx",
+        std::process::id(),
+        label(candidate)
+    ));
+
+    match candidate {
+        IndexCandidate::Annoy { n_trees } => {
+            let mut annoy = AnnoyIndex::with_trees(dimension, *n_trees);
+            let build = build_and_save(&mut annoy, corpus, &path)?;
+            let evaluation = measure(&annoy, queries, exact, k)?;
+            Ok(vec![build.evaluation(
+                "annoy",
+                format!("trees={}", n_trees),
+                evaluation,
+            )])
+        }
+        IndexCandidate::Hnsw { params, ef_search } => {
+            // 검색 폭은 검색에만 영향을 주므로 그래프는 한 번만 만듦
+            let mut hnsw = HnswIndex::new(dimension, *params);
+            let build = build_and_save(&mut hnsw, corpus, &path)?;
+            ef_search
+                .iter()
+                .map(|&ef| {
+                    hnsw.set_ef_search(ef);
+                    let evaluation = measure(&hnsw, queries, exact, k)?;
+                    Ok(build.evaluation(
+                        "hnsw",
+                        format!(
+                            "m={},ef_construction={},ef_search={}",
+                            params.m, params.ef_construction, ef
+                        ),
+                        evaluation,
+                    ))
+                })
+                .collect()
+        }
+    }
+}
+
+struct BuildResult {
+    items: usize,
+    build_ms: u64,
+    index_bytes: u64,
+}
+
+impl BuildResult {
+    fn evaluation(&self, index: &str, params: String, evaluation: QueryResult) -> IndexEvaluation {
+        IndexEvaluation {
+            index: index.to_string(),
+            params,
+            items: self.items,
+            build_ms: self.build_ms,
+            index_bytes: self.index_bytes,
+            recall: evaluation.recall,
+            mean_query_us: evaluation.mean_query_us,
+            p95_query_us: evaluation.p95_query_us,
+        }
+    }
+}
+
+struct QueryResult {
+    recall: f64,
+    mean_query_us: f64,
+    p95_query_us: f64,
+}
+
+// Annoy는 저장할 때 트리를 만들므로 저장까지 빌드 시간에 포함함
+fn build_and_save(
+    index: &mut dyn VectorIndex,
+    corpus: &[(i32, Vec<f32>)],
+    path: &Path,
+) -> Result<BuildResult, String> {
+    let started = Instant::now();
+    for (id, vector) in corpus {
+        index.add(*id, vector)?;
+    }
+    index.save(path)?;
+    let build_ms = started.elapsed().as_millis() as u64;
+
+    let index_bytes = fs::metadata(path).map(|m| m.len()).unwrap_or_default();
+    let _ = fs::remove_file(path);
+
+    Ok(BuildResult {
+        items: corpus.len(),
+        build_ms,
+        index_bytes,
+    })
+}
+
+fn measure(
+    index: &dyn VectorIndex,
+    queries: &[Vec<f32>],
+    exact: &[Vec<i32>],
+    k: usize,
+) -> Result<QueryResult, String> {
+    let mut latencies = Vec::with_capacity(queries.len());
+    let mut recall = 0.0;
+    for (query, exact) in queries.iter().zip(exact) {
+        let started = Instant::now();
+        let found = index.search(query, k)?;
+        latencies.push(started.elapsed());
+
+        let found = found.into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+        recall += recall_at_k(exact, &found);
+    }
+
+    Ok(QueryResult {
+        recall: recall / queries.len().max(1) as f64,
+        mean_query_us: mean_micros(&latencies),
+        p95_query_us: percentile_micros(&mut latencies, 0.95),
+    })
+}
+
+fn label(candidate: &IndexCandidate) -> String {
+    match candidate {
+        IndexCandidate::Annoy { n_trees } => format!("annoy-{}", n_trees),
+        IndexCandidate::Hnsw { params, .. } => {
+            format!("hnsw-{}-{}", params.m, params.ef_construction)
+        }
+    }
+}
+
+fn mean_micros(latencies: &[Duration]) -> f64 {
+    if latencies.is_empty() {
+        return 0.0;
+    }
+    latencies.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / latencies.len() as f64 / 1_000.0
+}
+
+fn percentile_micros(latencies: &mut [Duration], percentile: f64) -> f64 {
+    if latencies.is_empty() {
+        return 0.0;
+    }
+    latencies.sort();
+    let index = ((latencies.len() as f64 * percentile).ceil() as usize).clamp(1, latencies.len());
+    latencies[index - 1].as_nanos() as f64 / 1_000.0
+}
+
+/// `brute_force_top_k`에 넘길 수 있도록 벡터를 정규화함.
+pub fn normalized_corpus(corpus: Vec<(i32, Vec<f32>)>) -> Vec<(i32, Vec<f32>)> {
+    corpus
+        .into_iter()
+        .map(|(id, vector)| (id, normalized(&vector)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_exact_top_k_by_cosine() {
+        let corpus = vec![
+            (1, normalized(&[1.0, 0.0])),
+            (2, normalized(&[0.0, 1.0])),
+            (3, normalized(&[1.0, 1.0])),
+        ];
+
+        assert_eq!(brute_force_top_k(&corpus, &[2.0, 0.1], 2), vec![1, 3]);
+        assert_eq!(brute_force_top_k(&corpus, &[0.0, 3.0], 1), vec![2]);
+    }
+
+    #[test]
+    fn computes_recall_against_exact_results() {
+        assert_eq!(recall_at_k(&[1, 2, 3, 4], &[1, 5, 3, 6]), 0.5);
+        assert_eq!(recall_at_k(&[1, 2], &[2, 1]), 1.0);
+        assert_eq!(recall_at_k(&[], &[1]), 1.0);
+    }
+
+    #[test]
+    fn takes_percentile_of_latencies() {
+        let mut latencies = (1..=20).map(Duration::from_micros).collect::<Vec<_>>();
+        assert_eq!(percentile_micros(&mut latencies, 0.95), 19.0);
+        assert_eq!(mean_micros(&latencies), 10.5);
+    }
+}
@@ -0,0 +1,735 @@
+//! 순수 Rust HNSW(Hierarchical Navigable Small World) 인덱스.
+//!
+//! 벡터는 정규화해서 저장하고 거리는 코사인 거리(`1 - cos`)를 사용함.
+//! 삭제는 노드를 삭제 표시만 하고 그래프 탐색에는 계속 사용하며, 삭제 표시된 노드가
+//! 살아 있는 노드보다 많아지면 살아 있는 노드만으로 그래프를 다시 만듦.
+//!
+//! 파일 형식 (모든 정수와 실수는 little-endian):
+//!
+//! ```text
+//! magic            8 bytes  "OMNIHNSW"
+//! format_version   u32      1
+//! dimension        u32
+//! m                u32      레이어별 최대 이웃 수 (레이어 0은 2 * m)
+//! ef_construction  u32
+//! node_count       u32
+//! entry_point      u32      노드가 없으면 u32::MAX
+//! max_level        u32
+//! node_count번 반복:
+//!   id             i32      임베딩 ID
+//!   deleted        u8       1이면 삭제 표시
+//!   level          u32
+//!   vector         f32 * dimension (정규화된 벡터)
+//!   레이어 0..=level 마다:
+//!     count        u32
+//!     neighbors    u32 * count (노드 순번)
+//! ```
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet},
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::VectorIndex;
+
+const MAGIC: &[u8; 8] = b"OMNIHNSW";
+const FORMAT_VERSION: u32 = 1;
+const NO_ENTRY_POINT: u32 = u32::MAX;
+// 헤더 크기 (magic + u32 7개)
+const HEADER_SIZE: u64 = 8 + 4 * 7;
+// 노드 레벨 상한. 잘못된 파일의 레벨로 큰 메모리를 잡지 않도록 읽을 때도 검사함
+const MAX_LEVEL: usize = 16;
+
+#[derive(Debug, Clone, Copy)]
+pub struct HnswParams {
+    pub m: usize,
+    pub ef_construction: usize,
+    pub ef_search: usize,
+}
+
+impl Default for HnswParams {
+    fn default() -> Self {
+        Self {
+            m: 16,
+            ef_construction: 100,
+            ef_search: 64,
+        }
+    }
+}
+
+struct Node {
+    id: i32,
+    vector: Vec<f32>,
+    deleted: bool,
+    // 레이어별 이웃 노드 순번. 길이는 노드 레벨 + 1
+    neighbors: Vec<Vec<u32>>,
+}
+
+// 거리 순으로 정렬되는 후보. 거리가 같으면 노드 순번으로 비교함
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct HnswIndex {
+    dimension: usize,
+    params: HnswParams,
+    nodes: Vec<Node>,
+    // 임베딩 ID -> 살아 있는 노드 순번
+    ids: HashMap<i32, u32>,
+    entry_point: Option<u32>,
+    max_level: usize,
+    rng: StdRng,
+}
+
+impl HnswIndex {
+    pub fn new(dimension: usize, params: HnswParams) -> Self {
+        Self {
+            dimension,
+            params,
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            entry_point: None,
+            max_level: 0,
+            rng: StdRng::seed_from_u64(123),
+        }
+    }
+
+    /// 파일에서 인덱스를 읽음. 검색 시 `ef_search`는 `params`의 값을 사용함.
+    /// 손상된 파일은 패닉 대신 에러를 반환함.
+    pub fn load(path: &Path, params: HnswParams) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| e.to_string())?;
+        let file_len = file.metadata().map_err(|e| e.to_string())?.len();
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic).map_err(|e| e.to_string())?;
+        if &magic != MAGIC {
+            return Err(format!("{} is not an HNSW index", path.display()));
+        }
+        let format_version = read_u32(&mut reader)?;
+        if format_version != FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported HNSW format version {}",
+                format_version
+            ));
+        }
+
+        let dimension = read_u32(&mut reader)? as usize;
+        let m = read_u32(&mut reader)? as usize;
+        let ef_construction = read_u32(&mut reader)? as usize;
+        let node_count = read_u32(&mut reader)? as usize;
+        let entry_point = read_u32(&mut reader)?;
+        let max_level = read_u32(&mut reader)? as usize;
+        if max_level > MAX_LEVEL {
+            return Err(format!("Invalid max level {}", max_level));
+        }
+        if entry_point != NO_ENTRY_POINT && entry_point as usize >= node_count {
+            return Err(format!("Invalid entry point {}", entry_point));
+        }
+
+        // 노드 수는 파일에 남은 바이트로 담을 수 있는 만큼만 미리 할당함
+        let min_node_size = (4 + 1 + 4 + 4 * dimension as u64 + 4).max(1);
+        let capacity =
+            node_count.min((file_len.saturating_sub(HEADER_SIZE) / min_node_size) as usize);
+        let mut nodes = Vec::with_capacity(capacity);
+        let mut ids = HashMap::new();
+        for index in 0..node_count {
+            let id = read_i32(&mut reader)?;
+            let mut deleted = [0u8; 1];
+            reader.read_exact(&mut deleted).map_err(|e| e.to_string())?;
+            let level = read_u32(&mut reader)? as usize;
+            if level > max_level {
+                return Err(format!("Invalid level {} in node {}", level, index));
+            }
+            let vector = (0..dimension)
+                .map(|_| read_f32(&mut reader))
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut neighbors = Vec::with_capacity(level + 1);
+            for _ in 0..=level {
+                let count = read_u32(&mut reader)? as usize;
+                let layer = (0..count)
+                    .map(|_| read_u32(&mut reader))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if layer
+                    .iter()
+                    .any(|&neighbor| neighbor as usize >= node_count)
+                {
+                    return Err(format!("Invalid neighbor in node {}", index));
+                }
+                neighbors.push(layer);
+            }
+
+            let deleted = deleted[0] == 1;
+            if !deleted && ids.insert(id, index as u32).is_some() {
+                return Err(format!("Duplicate id {} in node {}", id, index));
+            }
+            nodes.push(Node {
+                id,
+                vector,
+                deleted,
+                neighbors,
+            });
+        }
+
+        // 이웃은 해당 레이어까지 있는 노드여야 하고, 진입점은 최상위 레이어에 있어야 함
+        for (index, node) in nodes.iter().enumerate() {
+            for (layer, neighbors) in node.neighbors.iter().enumerate() {
+                if neighbors
+                    .iter()
+                    .any(|&neighbor| nodes[neighbor as usize].neighbors.len() <= layer)
+                {
+                    return Err(format!("Invalid neighbor in node {}", index));
+                }
+            }
+        }
+        if entry_point != NO_ENTRY_POINT
+            && nodes[entry_point as usize].neighbors.len() != max_level + 1
+        {
+            return Err(format!("Invalid entry point {}", entry_point));
+        }
+
+        Ok(Self {
+            dimension,
+            params: HnswParams {
+                m,
+                ef_construction,
+                ef_search: params.ef_search,
+            },
+            ids,
+            entry_point: (entry_point != NO_ENTRY_POINT).then_some(entry_point),
+            max_level,
+            rng: StdRng::seed_from_u64(123 + node_count as u64),
+            nodes,
+        })
+    }
+
+    pub fn set_ef_search(&mut self, ef_search: usize) {
+        self.params.ef_search = ef_search.max(1);
+    }
+
+    /// 삭제 표시된 노드 수.
+    pub fn deleted(&self) -> usize {
+        self.nodes.len() - self.ids.len()
+    }
+
+    fn max_neighbors(&self, layer: usize) -> usize {
+        if layer == 0 {
+            self.params.m * 2
+        } else {
+            self.params.m
+        }
+    }
+
+    fn random_level(&mut self) -> usize {
+        let multiplier = 1.0 / (self.params.m.max(2) as f64).ln();
+        let uniform: f64 = self.rng.random::<f64>().max(f64::MIN_POSITIVE);
+        ((-uniform.ln() * multiplier).floor() as usize).min(MAX_LEVEL)
+    }
+
+    fn distance(&self, query: &[f32], node: u32) -> f32 {
+        1.0 - dot(query, &self.nodes[node as usize].vector)
+    }
+
+    /// 한 레이어에서 `entry_points`부터 탐욕 탐색해 가까운 노드를 최대 `ef`개 반환함 (가까운 순).
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: &[Candidate],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited = entry_points.iter().map(|c| c.node).collect::<HashSet<_>>();
+        let mut candidates = entry_points
+            .iter()
+            .map(|&c| Reverse(c))
+            .collect::<BinaryHeap<_>>();
+        let mut results = entry_points.iter().copied().collect::<BinaryHeap<_>>();
+        while results.len() > ef {
+            results.pop();
+        }
+
+        while let Some(Reverse(candidate)) = candidates.pop() {
+            let furthest = results.peek().map(|c| c.distance).unwrap_or(f32::MAX);
+            if candidate.distance > furthest && results.len() >= ef {
+                break;
+            }
+
+            let node = &self.nodes[candidate.node as usize];
+            let Some(neighbors) = node.neighbors.get(layer) else {
+                continue;
+            };
+            for &neighbor in neighbors {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let distance = self.distance(query, neighbor);
+                let furthest = results.peek().map(|c| c.distance).unwrap_or(f32::MAX);
+                if results.len() < ef || distance < furthest {
+                    let next = Candidate {
+                        distance,
+                        node: neighbor,
+                    };
+                    candidates.push(Reverse(next));
+                    results.push(next);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+
+        results.into_sorted_vec()
+    }
+
+    /// 후보 중 서로 가깝게 몰려 있지 않은 이웃을 최대 `max`개 고름 (HNSW 논문의 휴리스틱).
+    /// 다양한 방향의 이웃을 남겨 그래프가 끊어지지 않도록 함.
+    fn select_neighbors(&self, candidates: &[Candidate], max: usize) -> Vec<u32> {
+        let mut selected: Vec<Candidate> = Vec::with_capacity(max);
+        let mut skipped = Vec::new();
+
+        for &candidate in candidates {
+            if selected.len() >= max {
+                break;
+            }
+            let vector = &self.nodes[candidate.node as usize].vector;
+            let diverse = selected
+                .iter()
+                .all(|s| self.distance(vector, s.node) > candidate.distance);
+            if diverse {
+                selected.push(candidate);
+            } else {
+                skipped.push(candidate);
+            }
+        }
+        // 이웃이 모자라면 제외했던 후보로 채움
+        for candidate in skipped {
+            if selected.len() >= max {
+                break;
+            }
+            selected.push(candidate);
+        }
+
+        selected.into_iter().map(|c| c.node).collect()
+    }
+
+    fn connect(&mut self, node: u32, neighbors: &[u32], layer: usize) {
+        self.nodes[node as usize].neighbors[layer] = neighbors.to_vec();
+
+        let max = self.max_neighbors(layer);
+        for &neighbor in neighbors {
+            let links = &mut self.nodes[neighbor as usize].neighbors[layer];
+            links.push(node);
+            if links.len() <= max {
+                continue;
+            }
+
+            // 이웃 수가 넘치면 다시 골라서 줄임
+            let vector = self.nodes[neighbor as usize].vector.clone();
+            let mut candidates = self.nodes[neighbor as usize].neighbors[layer]
+                .iter()
+                .map(|&n| Candidate {
+                    distance: self.distance(&vector, n),
+                    node: n,
+                })
+                .collect::<Vec<_>>();
+            candidates.sort();
+            let pruned = self.select_neighbors(&candidates, max);
+            self.nodes[neighbor as usize].neighbors[layer] = pruned;
+        }
+    }
+
+    fn insert(&mut self, id: i32, vector: Vec<f32>) {
+        let level = self.random_level();
+        let node = self.nodes.len() as u32;
+        self.nodes.push(Node {
+            id,
+            vector,
+            deleted: false,
+            neighbors: vec![Vec::new(); level + 1],
+        });
+        self.ids.insert(id, node);
+
+        let Some(entry_point) = self.entry_point else {
+            self.entry_point = Some(node);
+            self.max_level = level;
+            return;
+        };
+
+        let query = self.nodes[node as usize].vector.clone();
+        let mut entry_points = vec![Candidate {
+            distance: self.distance(&query, entry_point),
+            node: entry_point,
+        }];
+        // 새 노드 레벨보다 위 레이어에서는 가장 가까운 노드 하나만 따라 내려감
+        for layer in (level + 1..=self.max_level).rev() {
+            entry_points = self.search_layer(&query, &entry_points, 1, layer);
+        }
+        for layer in (0..=level.min(self.max_level)).rev() {
+            let candidates =
+                self.search_layer(&query, &entry_points, self.params.ef_construction, layer);
+            let neighbors = self.select_neighbors(&candidates, self.max_neighbors(layer));
+            self.connect(node, &neighbors, layer);
+            entry_points = candidates;
+        }
+
+        if level > self.max_level {
+            self.max_level = level;
+            self.entry_point = Some(node);
+        }
+    }
+
+    // 삭제 표시된 노드를 빼고 살아 있는 노드만으로 그래프를 다시 만듦
+    fn compact(&mut self) {
+        let nodes = std::mem::take(&mut self.nodes);
+        self.ids.clear();
+        self.entry_point = None;
+        self.max_level = 0;
+
+        for node in nodes.into_iter().filter(|node| !node.deleted) {
+            self.insert(node.id, node.vector);
+        }
+    }
+
+    fn compact_if_fragmented(&mut self) {
+        if self.deleted() > self.ids.len() {
+            self.compact();
+        }
+    }
+}
+
+impl VectorIndex for HnswIndex {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// 같은 ID가 있으면 이전 노드를 삭제 표시하고 새 벡터로 추가함.
+    /// 교체로 삭제 표시된 노드가 많아지면 `remove`와 같이 그래프를 다시 만듦.
+    fn add(&mut self, id: i32, vector: &[f32]) -> Result<(), String> {
+        if vector.len() != self.dimension {
+            return Err(format!(
+                "Expected dimension {}, got {}",
+                self.dimension,
+                vector.len()
+            ));
+        }
+
+        let replaced = match self.ids.remove(&id) {
+            Some(previous) => {
+                self.nodes[previous as usize].deleted = true;
+                true
+            }
+            None => false,
+        };
+        self.insert(id, normalized(vector));
+        if replaced {
+            self.compact_if_fragmented();
+        }
+        Ok(())
+    }
+
+    fn remove(&mut self, id: i32) -> Result<bool, String> {
+        let Some(node) = self.ids.remove(&id) else {
+            return Ok(false);
+        };
+        self.nodes[node as usize].deleted = true;
+        self.compact_if_fragmented();
+        Ok(true)
+    }
+
+    fn retain(&mut self, ids: &HashSet<i32>) -> Result<usize, String> {
+        let removed = self
+            .ids
+            .keys()
+            .filter(|id| !ids.contains(id))
+            .copied()
+            .collect::<Vec<_>>();
+        for id in &removed {
+            if let Some(node) = self.ids.remove(id) {
+                self.nodes[node as usize].deleted = true;
+            }
+        }
+        self.compact_if_fragmented();
+        Ok(removed.len())
+    }
+
+    fn search(&self, query: &[f32], k: usize) -> Result<Vec<(i32, f32)>, String> {
+        if query.len() != self.dimension {
+            return Err(format!(
+                "Expected dimension {}, got {}",
+                self.dimension,
+                query.len()
+            ));
+        }
+        let Some(entry_point) = self.entry_point else {
+            return Ok(Vec::new());
+        };
+
+        let query = normalized(query);
+        let mut entry_points = vec![Candidate {
+            distance: self.distance(&query, entry_point),
+            node: entry_point,
+        }];
+        for layer in (1..=self.max_level).rev() {
+            entry_points = self.search_layer(&query, &entry_points, 1, layer);
+        }
+        // 삭제 표시된 노드는 결과에서 빠지므로, 삭제된 노드가 있으면 두 배로 넓게 탐색함
+        let mut ef = self.params.ef_search.max(k);
+        if self.deleted() > 0 {
+            ef *= 2;
+        }
+
+        Ok(self
+            .search_layer(&query, &entry_points, ef, 0)
+            .into_iter()
+            .filter_map(|c| {
+                let node = &self.nodes[c.node as usize];
+                (!node.deleted).then_some((node.id, c.distance))
+            })
+            .take(k)
+            .collect())
+    }
+
+    fn save(&mut self, path: &Path) -> Result<(), String> {
+        let mut writer = BufWriter::new(File::create(path).map_err(|e| e.to_string())?);
+        let mut write = |bytes: &[u8]| writer.write_all(bytes).map_err(|e| e.to_string());
+
+        write(MAGIC)?;
+        write(&FORMAT_VERSION.to_le_bytes())?;
+        write(&(self.dimension as u32).to_le_bytes())?;
+        write(&(self.params.m as u32).to_le_bytes())?;
+        write(&(self.params.ef_construction as u32).to_le_bytes())?;
+        write(&(self.nodes.len() as u32).to_le_bytes())?;
+        write(&self.entry_point.unwrap_or(NO_ENTRY_POINT).to_le_bytes())?;
+        write(&(self.max_level as u32).to_le_bytes())?;
+
+        for node in &self.nodes {
+            write(&node.id.to_le_bytes())?;
+            write(&[node.deleted as u8])?;
+            write(&((node.neighbors.len() - 1) as u32).to_le_bytes())?;
+            for value in &node.vector {
+                write(&value.to_le_bytes())?;
+            }
+            for layer in &node.neighbors {
+                write(&(layer.len() as u32).to_le_bytes())?;
+                for neighbor in layer {
+                    write(&neighbor.to_le_bytes())?;
+                }
+            }
+        }
+
+        writer.flush().map_err(|e| e.to_string())?;
+        writer.get_ref().sync_all().map_err(|e| e.to_string())
+    }
+}
+
+pub(super) fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+pub(super) fn normalized(vector: &[f32]) -> Vec<f32> {
+    let norm = dot(vector, vector).sqrt();
+    if norm == 0.0 {
+        return vector.to_vec();
+    }
+    vector.iter().map(|v| v / norm).collect()
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32, String> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes).map_err(|e| e.to_string())?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_i32(reader: &mut impl Read) -> Result<i32, String> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes).map_err(|e| e.to_string())?;
+    Ok(i32::from_le_bytes(bytes))
+}
+
+fn read_f32(reader: &mut impl Read) -> Result<f32, String> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes).map_err(|e| e.to_string())?;
+    Ok(f32::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_vectors(count: usize, dimension: usize) -> Vec<Vec<f32>> {
+        let mut rng = StdRng::seed_from_u64(7);
+        (0..count)
+            .map(|_| (0..dimension).map(|_| rng.random::<f32>() - 0.5).collect())
+            .collect()
+    }
+
+    fn brute_force(vectors: &[Vec<f32>], query: &[f32], k: usize) -> Vec<i32> {
+        let query = normalized(query);
+        let mut distances = vectors
+            .iter()
+            .enumerate()
+            .map(|(id, v)| (id as i32, 1.0 - dot(&query, &normalized(v))))
+            .collect::<Vec<_>>();
+        distances.sort_by(|a, b| a.1.total_cmp(&b.1));
+        distances.into_iter().take(k).map(|(id, _)| id).collect()
+    }
+
+    fn build(vectors: &[Vec<f32>]) -> HnswIndex {
+        let mut index = HnswIndex::new(vectors[0].len(), HnswParams::default());
+        for (id, vector) in vectors.iter().enumerate() {
+            index.add(id as i32, vector).unwrap();
+        }
+        index
+    }
+
+    #[test]
+    fn finds_nearest_neighbors_like_brute_force() {
+        let vectors = random_vectors(500, 16);
+        let index = build(&vectors);
+        assert_eq!(index.len(), 500);
+
+        let queries = random_vectors(20, 16);
+        let mut hits = 0;
+        for query in &queries {
+            let expected = brute_force(&vectors, query, 10);
+            let found = index.search(query, 10).unwrap();
+            hits += found.iter().filter(|(id, _)| expected.contains(id)).count();
+        }
+        // recall@10 >= 0.9
+        assert!(hits >= 180, "recall@10 too low: {}/200", hits);
+    }
+
+    #[test]
+    fn removed_items_are_not_returned() {
+        let vectors = random_vectors(100, 8);
+        let mut index = build(&vectors);
+
+        assert!(index.remove(3).unwrap());
+        assert!(!index.remove(3).unwrap());
+        let found = index.search(&vectors[3], 5).unwrap();
+        assert!(found.iter().all(|(id, _)| *id != 3));
+
+        // 같은 ID로 다시 추가하면 새 벡터로 바뀜
+        index.add(3, &vectors[50]).unwrap();
+        let found = index.search(&vectors[50], 2).unwrap();
+        assert!(found.iter().any(|(id, _)| *id == 3));
+        assert_eq!(index.len(), 100);
+    }
+
+    #[test]
+    fn compacts_when_most_items_are_removed() {
+        let vectors = random_vectors(100, 8);
+        let mut index = build(&vectors);
+
+        let keep = (0..40).collect::<HashSet<_>>();
+        assert_eq!(index.retain(&keep).unwrap(), 60);
+        assert_eq!(index.len(), 40);
+        assert_eq!(index.deleted(), 0);
+
+        let found = index.search(&vectors[10], 1).unwrap();
+        assert_eq!(found[0].0, 10);
+    }
+
+    #[test]
+    fn compacts_when_most_items_are_replaced() {
+        let vectors = random_vectors(100, 8);
+        let mut index = build(&vectors);
+
+        // 교체만 120번 하면 삭제 표시가 살아 있는 노드 수를 넘으므로 중간에 다시 만들어짐
+        for _ in 0..2 {
+            for id in 0..60 {
+                index.add(id, &vectors[99 - id as usize]).unwrap();
+            }
+        }
+        assert_eq!(index.len(), 100);
+        assert!(index.deleted() < 60, "not compacted: {}", index.deleted());
+
+        let found = index.search(&vectors[99], 2).unwrap();
+        assert!(found.iter().any(|(id, _)| *id == 0));
+    }
+
+    #[test]
+    fn loads_saved_index() {
+        let vectors = random_vectors(200, 8);
+        let mut index = build(&vectors);
+        index.remove(0).unwrap();
+
+        let path = std::env::temp_dir().join(format!("omninews-hnsw-{}.hnsw", std::process::id()));
+        index.save(&path).unwrap();
+        let loaded = HnswIndex::load(&path, HnswParams::default()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.dimension(), 8);
+        assert_eq!(loaded.len(), 199);
+        assert_eq!(loaded.deleted(), 1);
+        for query in vectors.iter().take(10) {
+            assert_eq!(
+                loaded.search(query, 5).unwrap(),
+                index.search(query, 5).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_corrupted_index_without_panicking() {
+        let header = |node_count: u32, entry_point: u32, max_level: u32| {
+            let mut bytes = MAGIC.to_vec();
+            for value in [
+                FORMAT_VERSION,
+                8,
+                16,
+                100,
+                node_count,
+                entry_point,
+                max_level,
+            ] {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            bytes
+        };
+        let path = std::env::temp_dir().join(format!(
+            "omninews-hnsw-corrupted-{}.hnsw",
+            std::process::id()
+        ));
+
+        for bytes in [
+            // 노드가 없는데 노드 수가 매우 큼
+            header(u32::MAX, 0, 0),
+            // 진입점이 노드 수를 넘음
+            header(0, 3, 0),
+            // 최대 레벨이 상한을 넘음
+            header(1, 0, u32::MAX),
+        ] {
+            std::fs::write(&path, bytes).unwrap();
+            assert!(HnswIndex::load(&path, HnswParams::default()).is_err());
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,140 @@
+pub mod annoy;
+pub mod evaluation;
+pub mod hnsw;
+
+use std::{collections::HashSet, env, path::Path};
+
+use crate::{annoy_warn, config::env::get_env_or};
+
+use self::{
+    annoy::AnnoyIndex,
+    hnsw::{HnswIndex, HnswParams},
+};
+
+/// 임베딩 ID와 벡터를 담는 근사 최근접 이웃 인덱스.
+/// 검색 결과는 가까운 순서의 `(임베딩 ID, 거리)`이며 거리 척도는 구현마다 다름.
+pub trait VectorIndex {
+    fn dimension(&self) -> usize;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn add(&mut self, id: i32, vector: &[f32]) -> Result<(), String>;
+
+    /// 항목을 삭제하고, 있었는지 여부를 반환함.
+    fn remove(&mut self, id: i32) -> Result<bool, String>;
+
+    /// `ids`에 없는 항목을 모두 삭제하고 삭제한 개수를 반환함.
+    fn retain(&mut self, ids: &HashSet<i32>) -> Result<usize, String>;
+
+    fn search(&self, query: &[f32], k: usize) -> Result<Vec<(i32, f32)>, String>;
+
+    fn save(&mut self, path: &Path) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorIndexType {
+    // rannoy(C++ Annoy). 한 번 만들면 바꿀 수 없어 변경이 있으면 전체를 다시 만듦
+    Annoy,
+    // 순수 Rust HNSW. 이전 버전을 읽어 바뀐 임베딩만 추가/삭제함
+    Hnsw,
+}
+
+impl VectorIndexType {
+    fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "annoy" => Some(Self::Annoy),
+            "hnsw" => Some(Self::Hnsw),
+            _ => None,
+        }
+    }
+
+    /// `VECTOR_INDEX_TYPE`(`annoy`, `hnsw`, 기본 `annoy`).
+    pub fn from_env() -> Self {
+        env::var("VECTOR_INDEX_TYPE")
+            .ok()
+            .filter(|name| !name.trim().is_empty())
+            .map(|name| {
+                Self::parse(&name).unwrap_or_else(|| {
+                    annoy_warn!("[VectorIndex] Unknown index type {}, use annoy", name);
+                    Self::Annoy
+                })
+            })
+            .unwrap_or(Self::Annoy)
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Annoy => "ann",
+            Self::Hnsw => "hnsw",
+        }
+    }
+
+    /// 이전 버전 파일을 읽어 바뀐 항목만 반영할 수 있는지 여부.
+    pub fn is_incremental(&self) -> bool {
+        matches!(self, Self::Hnsw)
+    }
+
+    /// 검색 결과의 거리를 코사인 유사도로 바꿈. Annoy(angular)는 정규화된 벡터 사이의
+    /// 유클리드 거리(`sqrt(2 - 2cos)`), HNSW는 코사인 거리(`1 - cos`)를 반환함.
+    pub fn similarity(&self, distance: f32) -> f32 {
+        match self {
+            Self::Annoy => 1.0 - distance * distance / 2.0,
+            Self::Hnsw => 1.0 - distance,
+        }
+    }
+
+    pub fn create(&self, dimension: usize) -> Box<dyn VectorIndex> {
+        match self {
+            Self::Annoy => Box::new(AnnoyIndex::new(dimension)),
+            Self::Hnsw => Box::new(HnswIndex::new(dimension, hnsw_params())),
+        }
+    }
+
+    pub fn load(&self, path: &Path, dimension: usize) -> Result<Box<dyn VectorIndex>, String> {
+        let index: Box<dyn VectorIndex> = match self {
+            Self::Annoy => Box::new(AnnoyIndex::load(path, dimension)?),
+            Self::Hnsw => Box::new(HnswIndex::load(path, hnsw_params())?),
+        };
+        if index.dimension() != dimension {
+            return Err(format!(
+                "{} has dimension {}, expected {}",
+                path.display(),
+                index.dimension(),
+                dimension
+            ));
+        }
+        Ok(index)
+    }
+}
+
+/// `HNSW_M`, `HNSW_EF_CONSTRUCTION`, `HNSW_EF_SEARCH`. `m`과 `ef_construction`은 새로 만드는
+/// 인덱스에만 적용되고, 파일에서 읽은 인덱스는 파일에 기록된 값을 사용함.
+pub fn hnsw_params() -> HnswParams {
+    let default = HnswParams::default();
+    HnswParams {
+        m: get_env_or("HNSW_M", default.m).max(2),
+        ef_construction: get_env_or("HNSW_EF_CONSTRUCTION", default.ef_construction).max(1),
+        ef_search: get_env_or("HNSW_EF_SEARCH", default.ef_search).max(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_distance_to_cosine_similarity() {
+        // 같은 방향, 직교, 반대 방향 벡터 사이의 거리
+        assert_eq!(VectorIndexType::Annoy.similarity(0.0), 1.0);
+        assert!(VectorIndexType::Annoy.similarity(2f32.sqrt()).abs() < 1e-6);
+        assert_eq!(VectorIndexType::Annoy.similarity(2.0), -1.0);
+
+        assert_eq!(VectorIndexType::Hnsw.similarity(0.0), 1.0);
+        assert_eq!(VectorIndexType::Hnsw.similarity(1.0), 0.0);
+        assert_eq!(VectorIndexType::Hnsw.similarity(2.0), -1.0);
+    }
+}